@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+use crate::{Error, InputSource};
+
+/* answers are stored next to the inputs directory, one file per day with
+   the answer of part a in the first and part b in the second line */
+pub fn answers_dir(source: &InputSource) -> Result<PathBuf, Error> {
+    match source {
+        InputSource::Directory(dir) => {
+            let parent = dir.parent().unwrap_or(Path::new(""));
+            Ok(parent.join("answers"))
+        },
+        _ => Err(Error::Usage("answers can only be verified with an inputs directory".to_string())),
+    }
+}
+
+pub fn parse(input: &str) -> [Option<String>; 2] {
+    let mut lines = input.lines()
+                         .map(str::trim)
+                         .map(|line| Some(line.to_string()).filter(|line| !line.is_empty()));
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+pub fn read(dir: &Path, day: u8) -> Result<[Option<String>; 2], Error> {
+    let path = dir.join(format!("day{}", day));
+    match std::fs::read_to_string(&path) {
+        Ok(content) => Ok(parse(&content)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok([None, None]),
+        Err(err) => Err(Error::Io(path, err)),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Status {
+    pub fn check(expected: Option<&str>, actual: &str) -> Status {
+        match expected {
+            Some(expected) if expected == actual => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Unknown,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(parse("35\n46\n"), [Some("35".to_string()), Some("46".to_string())]);
+        assert_eq!(parse("19114\n"), [Some("19114".to_string()), None]);
+        assert_eq!(parse(""), [None, None]);
+
+        assert_eq!(Status::check(Some("35"), "35"), Status::Pass);
+        assert_eq!(Status::check(Some("35"), "36"), Status::Fail);
+        assert_eq!(Status::check(None, "35"), Status::Unknown);
+
+        assert_eq!(answers_dir(&InputSource::Directory(PathBuf::from("inputs"))).unwrap(),
+                   PathBuf::from("answers"));
+        assert_eq!(answers_dir(&InputSource::Directory(PathBuf::from("/data/aoc/inputs"))).unwrap(),
+                   PathBuf::from("/data/aoc/answers"));
+        assert!(answers_dir(&InputSource::Stdin).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/* states repeat with the given period after the first `prefix` steps */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /* earliest step that has the same state as the given one */
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.period
+        }
+    }
+}
+
+/* simulates until a state repeats or the target step is reached; states are
+   compared by a key, so that the state itself doesn't need to be hashable */
+fn simulate<S, K: Hash + Eq>(initial: S,
+                             mut step: impl FnMut(&S) -> S,
+                             mut key: impl FnMut(&S) -> K,
+                             target: Option<usize>) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let current = states.len() - 1;
+        if Some(current) == target {
+            return (states, None);
+        }
+        if let Some(&first) = seen.get(&key(&states[current])) {
+            states.pop();
+            return (states, Some(Cycle { prefix: first, period: current - first }));
+        }
+        seen.insert(key(&states[current]), current);
+        let next = step(&states[current]);
+        states.push(next);
+    }
+}
+
+pub fn find_cycle<S: Hash + Eq + Clone>(initial: S, step: impl FnMut(&S) -> S) -> Cycle {
+    find_cycle_by_key(initial, step, S::clone)
+}
+
+pub fn find_cycle_by_key<S, K: Hash + Eq>(initial: S,
+                                          step: impl FnMut(&S) -> S,
+                                          key: impl FnMut(&S) -> K) -> Cycle {
+    simulate(initial, step, key, None).1.unwrap()
+}
+
+pub fn state_at<S: Hash + Eq + Clone>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    state_at_by_key(initial, step, S::clone, n)
+}
+
+pub fn state_at_by_key<S, K: Hash + Eq>(initial: S,
+                                        step: impl FnMut(&S) -> S,
+                                        key: impl FnMut(&S) -> K,
+                                        n: usize) -> S {
+    let (mut states, cycle) = simulate(initial, step, key, Some(n));
+    let index = match cycle {
+        Some(cycle) => cycle.reduce(n),
+        None => n,
+    };
+    states.swap_remove(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        /* 0, 1, 2, 3, 4, 5, 6, 2, 3, ... */
+        let step = |&x: &u32| if x == 6 { 2 } else { x + 1 };
+        assert_eq!(find_cycle(0, step), Cycle { prefix: 2, period: 5 });
+        assert_eq!(state_at(0, step, 0), 0);
+        assert_eq!(state_at(0, step, 4), 4);
+        assert_eq!(state_at(0, step, 7), 2);
+        assert_eq!(state_at(0, step, 1_000_000_000), 2 + (1_000_000_000 - 2) % 5);
+
+        /* pure cycle without prefix */
+        assert_eq!(find_cycle(0, |&x: &u32| (x + 3) % 9), Cycle { prefix: 0, period: 3 });
+
+        /* the state carries a step counter that must not be part of the key */
+        let step = |&(n, x): &(u64, u32)| (n + 1, (x * 2) % 10);
+        assert_eq!(find_cycle_by_key((0, 3), step, |&(_, x)| x), Cycle { prefix: 1, period: 4 });
+        assert_eq!(state_at_by_key((0, 3), step, |&(_, x)| x, 1_000_000_000).1, 8);
+    }
+}
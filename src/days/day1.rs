@@ -1,9 +1,26 @@
-static DAY: u8 = 1;
+use std::fmt::Display;
+use crate::{Error, Solution};
 
-fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", sum_digits(&input, false));
-    println!("{DAY}b: {}", sum_digits(&input, true));
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        /* lines are scanned by byte offset */
+        crate::parse_lines(input, |line| {
+            if line.is_ascii() { Ok(line.to_string()) } else { Err("expected ASCII text".to_string()) }
+        })
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_digits(input, false))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_digits(input, true))
+    }
 }
 
 fn starts_with_number(word: &str, consider_words: bool) -> Option<u32> {
@@ -67,5 +84,6 @@ mod tests {
             "7pqrstsixteen",
         ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
         assert_eq!(sum_digits(&input, true), 281);
+        assert!(Day1::parse("1abc2\nfünf3").is_err());
     }
 }
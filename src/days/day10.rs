@@ -0,0 +1,623 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+use crate::geometry::{self, Direction, Position};
+use crate::search;
+use crate::{Error, Grid, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Pipes;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Pipes::new(&crate::lines(input)).map_err(|err| Error::Invalid(err.to_string()))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(steps_to_farthest(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(enclosed_tiles(input, Method::Area))
+    }
+
+    fn render(input: &Self::Input) -> Option<String> {
+        Some(render_map(input))
+    }
+}
+
+/* ways of counting the tiles enclosed by the loop; part b always uses Area,
+   Tracer is the reference it is tested against and what the render shades */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Method {
+    /* walk along the loop marking the tiles on its inner side, then fill */
+    Tracer,
+    /* loop area by the shoelace formula, tiles inside by Pick's theorem */
+    Area,
+}
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+fn pipe_directions(c: char) -> Option<[Direction; 2]> {
+    match c {
+        '|' => Some([Direction::South, Direction::North]),
+        '-' => Some([Direction::West, Direction::East]),
+        'L' => Some([Direction::North, Direction::East]),
+        'J' => Some([Direction::North, Direction::West]),
+        '7' => Some([Direction::South, Direction::West]),
+        'F' => Some([Direction::South, Direction::East]),
+        _ => None,
+    }
+}
+
+/* the pipe connecting the two directions, in either order */
+fn pipe_shape(directions: [Direction; 2]) -> char {
+    PIPES.into_iter()
+         .find(|&pipe| {
+             let pipe_directions = pipe_directions(pipe).unwrap();
+             pipe_directions == directions || pipe_directions == [directions[1], directions[0]]
+         })
+         .unwrap()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PipeError {
+    Malformed(String),
+    InvalidTile(Position, char),
+    MissingStart,
+    MultipleStarts(Position, Position),
+    /* directions from the start to neighbouring pipes that connect back */
+    StartConnections(Vec<Direction>),
+    StartNotOnLoop,
+}
+
+impl Display for PipeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PipeError::Malformed(message) => write!(f, "{}", message),
+            PipeError::InvalidTile(pos, c) => write!(f, "unexpected tile {:?} at {},{}", c, pos.x, pos.y),
+            PipeError::MissingStart => write!(f, "no start tile"),
+            PipeError::MultipleStarts(first, second) =>
+                write!(f, "start tiles at {},{} and {},{}", first.x, first.y, second.x, second.y),
+            PipeError::StartConnections(directions) =>
+                write!(f, "start connects to {} pipes {:?}, expected 2", directions.len(), directions),
+            PipeError::StartNotOnLoop => write!(f, "start isn't part of a loop"),
+        }
+    }
+}
+
+/* the pipe under the start, the loops that are left after removing the pipes
+   leading nowhere (the main loop through the start first), and the removed
+   pipes */
+#[derive(Debug, PartialEq, Eq)]
+pub struct PipeNetwork {
+    pub start_pipe: char,
+    pub loops: Vec<Vec<Position>>,
+    pub dead_ends: Vec<Position>,
+}
+
+pub fn pipe_network(input: &[String]) -> Result<PipeNetwork, PipeError> {
+    Pipes::new(input).map(|pipes| pipes.network)
+}
+
+/* the validated pipe map: every pipe as given, the network they form and the
+   map reduced to the main loop */
+pub struct Pipes {
+    all_pipes: Grid<Option<[Direction; 2]>>,
+    network: PipeNetwork,
+    main_loop: Map,
+}
+
+impl Pipes {
+    fn new(input: &[String]) -> Result<Pipes, PipeError> {
+        let mut map = Map::new(input)?;
+        let all_pipes = map.pipes.clone();
+        let start_pipe = pipe_shape(map.pipes[map.start].unwrap());
+        let dead_ends = map.remove_disconnected_pipes();
+        if !map.is_pipe(map.start) {
+            return Err(PipeError::StartNotOnLoop);
+        }
+        let network = PipeNetwork { start_pipe, loops: map.loops(), dead_ends };
+        map.keep_loop(&network.loops[0]);
+        Ok(Pipes { all_pipes, network, main_loop: map })
+    }
+}
+
+#[derive(Clone)]
+struct Map {
+    start: Position,
+    pipes: Grid<Option<[Direction; 2]>>,
+    insides: HashSet<Position>,
+    path: Vec<Position>,
+}
+
+impl Map {
+    fn new(input: &[String]) -> Result<Map, PipeError> {
+        let tiles = Grid::from_lines(input, |c| c).map_err(|err| PipeError::Malformed(err.to_string()))?;
+        let mut start = None;
+        let mut pipes = Grid::new(tiles.width(), tiles.height(), None);
+        for (pos, &c) in tiles.iter() {
+            match c {
+                'S' => match start {
+                    Some(first) => return Err(PipeError::MultipleStarts(first, pos)),
+                    None => start = Some(pos),
+                },
+                '.' => continue,
+                pipe => pipes[pos] = Some(pipe_directions(pipe).ok_or(PipeError::InvalidTile(pos, pipe))?),
+            }
+        }
+        let start = start.ok_or(PipeError::MissingStart)?;
+
+        /* the start pipe connects exactly to the neighbours that connect back */
+        let mut map = Map { start, pipes, insides: HashSet::new(), path: Vec::new() };
+        let directions = Direction::ALL.into_iter()
+                                       .filter(|&direction| map.pos_connectable(&start.step(direction), direction))
+                                       .collect::<Vec<_>>();
+        if directions.len() != 2 {
+            return Err(PipeError::StartConnections(directions));
+        }
+        let start_pipe = pipe_shape([directions[0], directions[1]]);
+        map.pipes[start] = pipe_directions(start_pipe);
+        Ok(map)
+    }
+
+    fn is_pipe(&self, pos: Position) -> bool {
+        matches!(self.pipes.get(pos), Some(Some(_)))
+    }
+
+    fn pos_connectable(&self, pos: &Position, from_direction: Direction) -> bool {
+        matches!(self.pipes.get(*pos), Some(Some(directions)) if directions.contains(&from_direction.reverse()))
+    }
+
+    /* returns the removed pipes */
+    fn remove_disconnected_pipes(&mut self) -> Vec<Position> {
+        let mut removed = Vec::new();
+        let mut disconnected_pipes = Vec::new();
+        loop {
+            disconnected_pipes.clear();
+            for (pos, directions) in self.pipes.iter() {
+                if let Some(directions) = directions {
+                    if directions.iter().any(|&direction| !self.pos_connectable(&pos.step(direction), direction)) {
+                        disconnected_pipes.push(pos);
+                    }
+                }
+            }
+            for &pos in &disconnected_pipes {
+                self.pipes[pos] = None;
+            }
+            if disconnected_pipes.is_empty() {
+                break;
+            }
+            removed.extend_from_slice(&disconnected_pipes);
+        }
+        removed.sort_unstable_by_key(|pos| (pos.y, pos.x));
+        removed
+    }
+
+    /* every remaining pipe connects at both ends, so the pipes form separate
+       loops; each is listed from its first tile in reading order */
+    fn loops(&self) -> Vec<Vec<Position>> {
+        let mut positions = self.pipes.positions()
+                                      .filter(|&pos| self.is_pipe(pos))
+                                      .collect::<Vec<_>>();
+        positions.sort_by_key(|&pos| pos != self.start);
+
+        let mut seen = Grid::new(self.pipes.width(), self.pipes.height(), false);
+        let mut loops = Vec::new();
+        for first in positions {
+            if seen[first] {
+                continue;
+            }
+            let mut tiles = Vec::new();
+            let (mut pos, mut direction) = (first, self.pipes[first].unwrap()[0]);
+            loop {
+                tiles.push(pos);
+                seen[pos] = true;
+                let next_pos = pos.step(direction);
+                if next_pos == first {
+                    break;
+                }
+                direction = self.other_direction(&next_pos, direction.reverse());
+                pos = next_pos;
+            }
+            loops.push(tiles);
+        }
+        loops
+    }
+
+    fn other_direction(&self, pos: &Position, direction: Direction) -> Direction {
+        /* get the direction from the other end of the pipe */
+        let directions = self.pipes[*pos].unwrap();
+        if directions[0] == direction {
+            directions[1]
+        } else {
+            directions[0]
+        }
+    }
+
+    /* removes every pipe that isn't part of the given loop */
+    fn keep_loop(&mut self, tiles: &[Position]) {
+        let mut on_path = Grid::new(self.pipes.width(), self.pipes.height(), false);
+        for &pos in tiles {
+            on_path[pos] = true;
+        }
+        for pos in self.pipes.positions() {
+            if !on_path[pos] {
+                self.pipes[pos] = None;
+            }
+        }
+        self.path = tiles.to_vec();
+    }
+
+    fn enclosed_tiles(&mut self) -> usize {
+        let min_y_pos = self.pipes.positions().find(|&pos| self.is_pipe(pos)).unwrap();
+
+        /* start from a known "outside", so that we know where the inside is */
+        let mut pos = min_y_pos;
+        let directions = self.pipes[pos].unwrap();
+        let (mut direction, other_direction) = (directions[0], directions[1]);
+
+        let mut inside_direction = match direction {
+            Direction::North => panic!("can't happen"),
+            Direction::South => match other_direction {
+                Direction::North => panic!("can't happen"),
+                Direction::South => panic!("can't happen"),
+                Direction::West => Direction::West,
+                Direction::East => Direction::East,
+            },
+            Direction::West => match other_direction {
+                Direction::North => panic!("can't happen"),
+                Direction::South => Direction::South,
+                Direction::West => panic!("can't happen"),
+                Direction::East => Direction::South,
+            },
+            Direction::East => match other_direction {
+                Direction::North => panic!("can't happen"),
+                Direction::South => Direction::South,
+                Direction::West => Direction::South,
+                Direction::East => panic!("can't happen"),
+            },
+        };
+
+        loop {
+            let inside_pos = pos.step(inside_direction);
+            if !self.is_pipe(inside_pos) {
+                self.insides.insert(inside_pos);
+            }
+
+            let next_pos = pos.step(direction);
+
+            if next_pos == min_y_pos {
+                /* back at the beginning */
+                break;
+            }
+
+            let next_direction = self.other_direction(&next_pos, direction.reverse());
+
+            let mut inside_pos_before_curve = inside_pos;
+            inside_direction = match direction {
+                Direction::North => match next_direction {
+                    Direction::North => inside_direction,
+                    Direction::South => panic!("can't happen"),
+                    Direction::West => if inside_direction == Direction::West {
+                        Direction::South
+                    } else {
+                        inside_pos_before_curve = Position { x: next_pos.x + 1, y: next_pos.y };
+                        Direction::North
+                    },
+                    Direction::East => if inside_direction == Direction::West {
+                        inside_pos_before_curve = Position { x: next_pos.x - 1, y: next_pos.y };
+                        Direction::North
+                    } else {
+                        Direction::South
+                    },
+                },
+                Direction::South => match next_direction {
+                    Direction::North => panic!("can't happen"),
+                    Direction::South => inside_direction,
+                    Direction::West => if inside_direction == Direction::West {
+                        Direction::North
+                    } else {
+                        inside_pos_before_curve = Position { x: next_pos.x + 1, y: next_pos.y };
+                        Direction::South
+                    },
+                    Direction::East => if inside_direction == Direction::West {
+                        inside_pos_before_curve = Position { x: next_pos.x - 1, y: next_pos.y };
+                        Direction::South
+                    } else {
+                        Direction::North
+                    },
+                },
+                Direction::West => match next_direction {
+                    Direction::North => if inside_direction == Direction::North {
+                        Direction::East
+                    } else {
+                        inside_pos_before_curve = Position { x: next_pos.x, y: next_pos.y + 1 };
+                        Direction::West
+                    },
+                    Direction::South => if inside_direction == Direction::North {
+                        inside_pos_before_curve = Position { x: next_pos.x, y: next_pos.y - 1 };
+                        Direction::West
+                    } else {
+                        Direction::East
+                    },
+                    Direction::West => inside_direction,
+                    Direction::East => panic!("can't happen"),
+                },
+                Direction::East => match next_direction {
+                    Direction::North => if inside_direction == Direction::North {
+                        Direction::West
+                    } else {
+                        inside_pos_before_curve = Position { x: next_pos.x, y: next_pos.y + 1 };
+                        Direction::East
+                    },
+                    Direction::South => if inside_direction == Direction::North {
+                        inside_pos_before_curve = Position { x: next_pos.x, y: next_pos.y - 1 };
+                        Direction::East
+                    } else {
+                        Direction::West
+                    },
+                    Direction::West => panic!("can't happen"),
+                    Direction::East => inside_direction,
+                },
+            };
+            if !self.is_pipe(inside_pos_before_curve) {
+                self.insides.insert(inside_pos_before_curve);
+            }
+
+            pos = next_pos;
+            direction = next_direction;
+        }
+
+        let starts = self.insides.iter().copied().collect::<Vec<_>>();
+        self.insides = search::flood_fill(starts, |pos| {
+            self.pipes.neighbors4(*pos).filter(|&neigh| !self.is_pipe(neigh))
+        });
+
+        self.insides.len()
+    }
+
+    /* the loop tiles are the lattice points on the polygon's boundary, so the
+       enclosed tiles are exactly the interior points */
+    fn enclosed_tiles_by_area(&self) -> usize {
+        geometry::interior_points(&self.path).expect("the loop doesn't overlap itself") as usize
+    }
+}
+
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const REVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+fn box_drawing(directions: [Direction; 2]) -> char {
+    match pipe_shape(directions) {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        _ => unreachable!(),
+    }
+}
+
+/* a summary of the pipe network, then the main loop in box drawing characters
+   with the start in bold and the farthest tile reversed, enclosed tiles
+   shaded, dead ends in red and the pipes of other loops dimmed */
+pub fn render_map(pipes: &Pipes) -> String {
+    let network = &pipes.network;
+    let mut map = pipes.main_loop.clone();
+    map.enclosed_tiles();
+    let farthest = map.path[map.path.len() / 2];
+    let mut dead_ends = Grid::new(map.pipes.width(), map.pipes.height(), false);
+    for &pos in &network.dead_ends {
+        dead_ends[pos] = true;
+    }
+
+    let loop_lengths = network.loops.iter()
+                                    .map(|tiles| tiles.len().to_string())
+                                    .collect::<Vec<_>>();
+    let mut output = format!("start pipe {}, loop lengths {}, dead ends {}\n",
+                             network.start_pipe, loop_lengths.join(" "), network.dead_ends.len());
+    for (y, row) in map.pipes.rows().enumerate() {
+        for (x, &directions) in row.iter().enumerate() {
+            let pos = Position { x: x as isize, y: y as isize };
+            if let Some(directions) = directions {
+                let style = if pos == map.start { BOLD } else if pos == farthest { REVERSE } else { "" };
+                if style.is_empty() {
+                    output.push(box_drawing(directions));
+                } else {
+                    output.push_str(&format!("{}{}{}", style, box_drawing(directions), RESET));
+                }
+            } else if map.insides.contains(&pos) {
+                output.push('░');
+            } else if let Some(directions) = pipes.all_pipes[pos] {
+                let style = if dead_ends[pos] { RED } else { DIM };
+                output.push_str(&format!("{}{}{}", style, box_drawing(directions), RESET));
+            } else {
+                output.push(' ');
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn steps_to_farthest(pipes: &Pipes) -> usize {
+    pipes.network.loops[0].len() / 2
+}
+
+pub fn enclosed_tiles(pipes: &Pipes, method: Method) -> usize {
+    match method {
+        Method::Tracer => pipes.main_loop.clone().enclosed_tiles(),
+        Method::Area => pipes.main_loop.enclosed_tiles_by_area(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "-L|F7",
+            "7S-7|",
+            "L|7||",
+            "-L-J|",
+            "L|-JF",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        assert_eq!(steps_to_farthest(&input), 4);
+
+        let input = [
+            "7-F7-",
+            ".FJ|7",
+            "SJLL7",
+            "|F--J",
+            "LJ.LJ",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        assert_eq!(steps_to_farthest(&input), 8);
+    }
+
+    #[test]
+    fn test_network() {
+        let input = [
+            "-L|F7",
+            "7S-7|",
+            "L|7||",
+            "-L-J|",
+            "L|-JF",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let network = pipe_network(&input).unwrap();
+        assert_eq!(network.start_pipe, 'F');
+        assert_eq!(network.loops.len(), 1);
+        assert_eq!(network.loops[0].len(), 8);
+        assert_eq!(network.dead_ends.len(), 17);
+
+        let lines = |input: &[&str]| input.iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let pos = |x, y| Position::new(x, y);
+        let network = pipe_network(&lines(&["S7.F7", "LJ.LJ", "..-.."])).unwrap();
+        assert_eq!(network, PipeNetwork {
+            start_pipe: 'F',
+            loops: vec![
+                vec![pos(0, 0), pos(0, 1), pos(1, 1), pos(1, 0)],
+                vec![pos(3, 0), pos(3, 1), pos(4, 1), pos(4, 0)],
+            ],
+            dead_ends: vec![pos(2, 2)],
+        });
+        assert_eq!(pipe_network(&lines(&["F7", "LS"])).unwrap().start_pipe, 'J');
+
+        assert_eq!(pipe_network(&lines(&["S-", ".."])), Err(PipeError::StartConnections(vec![Direction::East])));
+        assert_eq!(pipe_network(&lines(&["-S7", ".LJ"])),
+                   Err(PipeError::StartConnections(vec![Direction::East, Direction::South, Direction::West])));
+        assert_eq!(pipe_network(&lines(&["S7", "L-"])), Err(PipeError::StartNotOnLoop));
+        assert_eq!(pipe_network(&lines(&["F7", "LJ"])), Err(PipeError::MissingStart));
+        assert_eq!(pipe_network(&lines(&["SS"])), Err(PipeError::MultipleStarts(pos(0, 0), pos(1, 0))));
+        assert_eq!(pipe_network(&lines(&["S7", "Lx"])), Err(PipeError::InvalidTile(pos(1, 1), 'x')));
+        assert!(matches!(pipe_network(&lines(&["S7", "L"])), Err(PipeError::Malformed(_))));
+        assert!(Day10::parse("S-\n..").is_err());
+    }
+
+    #[test]
+    fn test_enclosed() {
+        let input = [
+            "...........",
+            ".S-------7.",
+            ".|F-----7|.",
+            ".||.....||.",
+            ".||.....||.",
+            ".|L-7.F-J|.",
+            ".|..|.|..|.",
+            ".L--J.L--J.",
+            "...........",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        assert_eq!(enclosed_tiles(&input, Method::Tracer), 4);
+        assert_eq!(enclosed_tiles(&input, Method::Area), 4);
+
+        let input = [
+            "..........",
+            ".S------7.",
+            ".|F----7|.",
+            ".||....||.",
+            ".||....||.",
+            ".|L-7F-J|.",
+            ".|..||..|.",
+            ".L--JL--J.",
+            "..........",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        assert_eq!(enclosed_tiles(&input, Method::Tracer), 4);
+        assert_eq!(enclosed_tiles(&input, Method::Area), 4);
+
+        let input = [
+            ".F----7F7F7F7F-7....",
+            ".|F--7||||||||FJ....",
+            ".||.FJ||||||||L7....",
+            "FJL7L7LJLJ||LJ.L-7..",
+            "L--J.L7...LJS7F-7L7.",
+            "....F-J..F7FJ|L7L7L7",
+            "....L7.F7||L7|.L7L7|",
+            ".....|FJLJ|FJ|F7|.LJ",
+            "....FJL-7.||.||||...",
+            "....L---J.LJ.LJLJ...",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        assert_eq!(enclosed_tiles(&input, Method::Tracer), 8);
+        assert_eq!(enclosed_tiles(&input, Method::Area), 8);
+
+        let input = [
+            "FF7FSF7F7F7F7F7F---7",
+            "L|LJ||||||||||||F--J",
+            "FL-7LJLJ||||||LJL-77",
+            "F--JF--7||LJLJ7F7FJ-",
+            "L---JF-JLJ.||-FJLJJ7",
+            "|F|F-JF---7F7-L7L|7|",
+            "|FFJF7L7F-JF7|JL---7",
+            "7-L-JL7||F7|L7F-7F7|",
+            "L.L7LFJ|||||FJL7||LJ",
+            "L7JLJL-JLJLJL--JLJ.L",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        assert_eq!(enclosed_tiles(&input, Method::Tracer), 10);
+        assert_eq!(enclosed_tiles(&input, Method::Area), 10);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = [
+            ".F-7.F7",
+            ".S.|-LJ",
+            ".L-J...",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        let plain = |c: char| c.to_string();
+        let styled = |style: &str, c: char| format!("{}{}{}", style, c, RESET);
+        let expected = [
+            "start pipe |, loop lengths 8 4, dead ends 1".to_string(),
+            [plain(' '), plain('┌'), plain('─'), plain('┐'), plain(' '), styled(DIM, '┌'), styled(DIM, '┐')].concat(),
+            [plain(' '), styled(BOLD, '│'), plain('░'), styled(REVERSE, '│'), styled(RED, '─'), styled(DIM, '└'), styled(DIM, '┘')].concat(),
+            [plain(' '), plain('└'), plain('─'), plain('┘'), plain(' '), plain(' '), plain(' ')].concat(),
+        ];
+        assert_eq!(render_map(&input), expected.map(|line| line + "\n").concat());
+        assert!(Day10::render(&input).is_some());
+    }
+
+    #[test]
+    fn test_large() {
+        /* a single rectangular loop enclosing ninety thousand tiles */
+        let size = 302;
+        let mut input = vec![format!("S{}7", "-".repeat(size - 2))];
+        input.extend((2 .. size).map(|_| format!("|{}|", ".".repeat(size - 2))));
+        input.push(format!("L{}J", "-".repeat(size - 2)));
+        let input = Day10::parse(&input.join("\n")).unwrap();
+        let inside = (size - 2) * (size - 2);
+        assert_eq!(steps_to_farthest(&input), 2 * (size - 1));
+        assert_eq!(enclosed_tiles(&input, Method::Tracer), inside);
+        assert_eq!(enclosed_tiles(&input, Method::Area), inside);
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Display;
+use crate::geometry::Position;
+use crate::{Error, Grid, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = GalaxyMap;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        GalaxyMap::new(&crate::lines(input))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_path_lengths(input, 2))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_path_lengths(input, 1_000_000))
+    }
+}
+
+pub struct GalaxyMap {
+    image: Grid<bool>,
+}
+
+impl GalaxyMap {
+    fn new(input: &[String]) -> Result<GalaxyMap, Error> {
+        if let Some((i, line)) = input.iter().enumerate().find(|(_, line)| !line.chars().all(|c| c == '.' || c == '#')) {
+            return Err(Error::Parse { line: i + 1, text: line.clone(), message: "expected '.' or '#'".to_string() });
+        }
+        let image = Grid::from_lines(input, |c| c == '#')?;
+        Ok(GalaxyMap { image })
+    }
+
+    /* galaxy positions after every empty row and column grew to `factor` rows
+       or columns */
+    fn expand_space(&self, factor: isize) -> Vec<Position> {
+        /* offsets from the empty lines before each row or column */
+        let offsets = |empty: Vec<bool>| {
+            empty.iter()
+                 .scan(0, |offset, &empty| {
+                     let current = *offset;
+                     *offset += empty as isize * (factor - 1);
+                     Some(current)
+                 })
+                 .collect::<Vec<_>>()
+        };
+        let row_offsets = offsets(self.image.rows().map(|row| !row.contains(&true)).collect());
+        let column_offsets = offsets(self.image.columns().map(|mut column| !column.any(|&galaxy| galaxy)).collect());
+
+        self.image.iter()
+                  .filter(|&(_, &galaxy)| galaxy)
+                  .map(|(pos, _)| Position {
+                      x: pos.x + column_offsets[pos.x as usize],
+                      y: pos.y + row_offsets[pos.y as usize],
+                  })
+                  .collect()
+    }
+
+    fn _print_map(&self) {
+        println!("{}", self.image.render(|&galaxy| if galaxy { '#' } else { '.' }));
+    }
+}
+
+fn calculate_distances(galaxies: &[Position]) -> Vec<isize> {
+    let mut distances = Vec::new();
+    for (i, pos1) in galaxies.iter().enumerate() {
+        for pos2 in galaxies.iter().skip(i+1) {
+            distances.push(pos1.manhattan(*pos2) as isize);
+        }
+    }
+    distances
+}
+
+fn sum_path_lengths(galaxymap: &GalaxyMap, factor: isize) -> isize {
+    let galaxies = galaxymap.expand_space(factor);
+    calculate_distances(&galaxies).iter()
+                                  .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "...#......",
+            ".......#..",
+            "#.........",
+            "..........",
+            "......#...",
+            ".#........",
+            ".........#",
+            "..........",
+            ".......#..",
+            "#...#.....",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let galaxymap = Day11::parse(&input.join("\n")).unwrap();
+        assert_eq!(sum_path_lengths(&galaxymap, 2), 374);
+        assert_eq!(sum_path_lengths(&galaxymap, 10), 1030);
+        assert_eq!(sum_path_lengths(&galaxymap, 100), 8410);
+
+        assert!(Day11::parse("..#\n.x.").is_err());
+    }
+}
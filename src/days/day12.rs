@@ -0,0 +1,143 @@
+use std::fmt::Display;
+use crate::{Error, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        crate::lines(input).iter()
+                           .enumerate()
+                           .map(|(i, line)| Record::new(line).ok_or_else(|| Error::Parse {
+                               line: i + 1,
+                               text: line.clone(),
+                               message: "expected springs and group sizes".to_string(),
+                           }))
+                           .collect()
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_arrangements(input, 1))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_arrangements(input, 5))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    fn from(c: char) -> Option<Spring> {
+        match c {
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
+        }
+    }
+}
+
+pub struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    fn new(input: &str) -> Option<Record> {
+        let (springs, groups) = input.split_once(' ')?;
+        let springs = springs.chars()
+                             .map(Spring::from)
+                             .collect::<Option<Vec<_>>>()?;
+        let groups = groups.split(',')
+                           .map(|n| n.parse().ok().filter(|&n| n > 0))
+                           .collect::<Option<Vec<_>>>()?;
+        Some(Record { springs, groups })
+    }
+
+    /* the springs repeated `times` times joined by unknowns, and the groups
+       repeated as often */
+    fn unfold(&self, times: usize) -> Record {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * times);
+        for i in 0 .. times {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        let groups = self.groups.repeat(times);
+        Record { springs, groups }
+    }
+
+    fn arrangements(&self) -> u64 {
+        let mut memo = vec![vec![None; self.groups.len() + 1]; self.springs.len() + 1];
+        self.count(0, 0, &mut memo)
+    }
+
+    /* arrangements of springs[spring..] that match groups[group..] */
+    fn count(&self, spring: usize, group: usize, memo: &mut Vec<Vec<Option<u64>>>) -> u64 {
+        if spring >= self.springs.len() {
+            return (group == self.groups.len()) as u64;
+        }
+        if let Some(count) = memo[spring][group] {
+            return count;
+        }
+
+        let mut count = 0;
+        if self.springs[spring] != Spring::Damaged {
+            count += self.count(spring + 1, group, memo);
+        }
+        if self.springs[spring] != Spring::Operational && group < self.groups.len() {
+            /* the group has to fit here and be followed by an operational spring or the end */
+            let end = spring + self.groups[group];
+            if end <= self.springs.len()
+               && !self.springs[spring .. end].contains(&Spring::Operational)
+               && self.springs.get(end) != Some(&Spring::Damaged) {
+                count += self.count(end + 1, group + 1, memo);
+            }
+        }
+        memo[spring][group] = Some(count);
+        count
+    }
+}
+
+fn sum_arrangements(input: &[Record], times: usize) -> u64 {
+    input.iter()
+         .map(|record| record.unfold(times).arrangements())
+         .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "???.### 1,1,3",
+            ".??..??...?##. 1,1,3",
+            "?#?#?#?#?#?#?#? 1,3,1,6",
+            "????.#...#... 4,1,1",
+            "????.######..#####. 1,6,5",
+            "?###???????? 3,2,1",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let records = Day12::parse(&input.join("\n")).unwrap();
+        let counts = records.iter().map(Record::arrangements).collect::<Vec<_>>();
+        assert_eq!(counts, [1, 4, 1, 1, 4, 10]);
+        assert_eq!(sum_arrangements(&records, 1), 21);
+
+        let counts = records.iter().map(|record| record.unfold(5).arrangements()).collect::<Vec<_>>();
+        assert_eq!(counts, [1, 16384, 1, 16, 2500, 506250]);
+        assert_eq!(sum_arrangements(&records, 5), 525152);
+
+        assert!(Day12::parse("??x 1").is_err());
+        assert!(Day12::parse("??? 1,0").is_err());
+    }
+}
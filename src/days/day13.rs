@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use crate::{Error, Grid, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Map>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let input = crate::lines(input);
+        let mut maps = Vec::new();
+        let mut start = 0;
+        for block in input.split(|line| line.is_empty()) {
+            /* errors are reported relative to the block */
+            let map = Map::new(block).map_err(|error| match error {
+                Error::Parse { line, text, message } => Error::Parse { line: start + line, text, message },
+                error => error,
+            })?;
+            maps.push(map);
+            start += block.len() + 1;
+        }
+        Ok(maps)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        summarize_patterns(input)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        summarize_patterns_with_smudge(input)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Reflection {
+    fn summary(&self) -> usize {
+        match *self {
+            Reflection::Vertical(column) => column + 1,
+            Reflection::Horizontal(row) => (row + 1) * 100,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    pattern: Grid<bool>,
+}
+
+impl Map {
+    fn new(input: &[String]) -> Result<Map, Error> {
+        if let Some((i, line)) = input.iter().enumerate().find(|(_, line)| !line.chars().all(|c| c == '.' || c == '#')) {
+            return Err(Error::Parse { line: i + 1, text: line.clone(), message: "expected '.' or '#'".to_string() });
+        }
+        let pattern = Grid::from_lines(input, |c| c == '#')?;
+        if pattern.width() == 0 || pattern.height() == 0 {
+            return Err(Error::Invalid("empty pattern".to_string()));
+        }
+        Ok(Map { pattern })
+    }
+
+    fn _print_map(&self) {
+        println!("{}", self.pattern.render(|&rock| if rock { '#' } else { '.' }));
+    }
+
+    fn is_horizontal_reflection(&self, y: usize) -> bool {
+        for pair in (y+1 .. self.pattern.height()).zip((0 ..= y).rev()) {
+            if self.pattern.row(pair.0) != self.pattern.row(pair.1) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn is_vertical_reflection(&self, x: usize) -> bool {
+        for pair in (x+1 .. self.pattern.width()).zip((0 ..= x).rev()) {
+            if !self.pattern.column(pair.0).eq(self.pattern.column(pair.1)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn find_reflections(&self) -> HashSet<Reflection> {
+        let mut reflections = HashSet::new();
+        for y in 0 .. self.pattern.height() - 1 {
+            if self.is_horizontal_reflection(y) {
+                reflections.insert(Reflection::Horizontal(y));
+            }
+        }
+        for x in 0 .. self.pattern.width() - 1 {
+            if self.is_vertical_reflection(x) {
+                reflections.insert(Reflection::Vertical(x));
+            }
+        }
+        reflections
+    }
+
+    fn find_reflection_with_smudge(&self) -> Option<Reflection> {
+        let orig_reflection = self.find_reflections();
+        for pos in self.pattern.positions() {
+            let mut map = self.clone();
+            map.pattern[pos] = !map.pattern[pos];
+            let reflections = map.find_reflections();
+            let new_reflections = reflections.difference(&orig_reflection).collect::<Vec<_>>();
+            if !new_reflections.is_empty() {
+                return Some(*new_reflections[0]);
+            }
+        }
+        None
+    }
+}
+
+fn no_reflection(i: usize) -> Error {
+    Error::Invalid(format!("pattern {} has no line of reflection", i + 1))
+}
+
+fn summarize_patterns(maps: &[Map]) -> Result<usize, Error> {
+    maps.iter()
+        .enumerate()
+        .map(|(i, m)| m.find_reflections().iter().next().map(Reflection::summary).ok_or_else(|| no_reflection(i)))
+        .sum()
+}
+
+fn summarize_patterns_with_smudge(maps: &[Map]) -> Result<usize, Error> {
+    maps.iter()
+        .enumerate()
+        .map(|(i, m)| m.find_reflection_with_smudge().as_ref().map(Reflection::summary).ok_or_else(|| no_reflection(i)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "#.##..##.",
+            "..#.##.#.",
+            "##......#",
+            "##......#",
+            "..#.##.#.",
+            "..##..##.",
+            "#.#.##.#.",
+            "",
+            "#...##..#",
+            "#....#..#",
+            "..##..###",
+            "#####.##.",
+            "#####.##.",
+            "..##..###",
+            "#....#..#",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let maps = Day13::parse(&input.join("\n")).unwrap();
+        assert_eq!(summarize_patterns(&maps).unwrap(), 405);
+        assert_eq!(summarize_patterns_with_smudge(&maps).unwrap(), 400);
+
+        assert!(summarize_patterns(&Day13::parse("#.\n.#\n..").unwrap()).is_err());
+        assert!(summarize_patterns_with_smudge(&Day13::parse("#").unwrap()).is_err());
+
+        assert!(matches!(Day13::parse("#.\n..\n\n#.\n.x"), Err(Error::Parse { line: 5, .. })));
+        assert!(Day13::parse("#.\n\n\n#.").is_err());
+    }
+}
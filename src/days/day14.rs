@@ -0,0 +1,153 @@
+use std::fmt::Display;
+use crate::cycle;
+use crate::geometry::Position;
+use crate::{Error, Grid, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Map::new(&crate::lines(input))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(total_load(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(total_load_cycles(input))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Rock {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl Rock {
+    fn from(c: char) -> Option<Rock> {
+        match c {
+            'O' => Some(Rock::Round),
+            '#' => Some(Rock::Cube),
+            '.' => Some(Rock::Empty),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Map {
+    rocks: Grid<Rock>,
+}
+
+impl Map {
+    fn new(input: &[String]) -> Result<Map, Error> {
+        if let Some((i, line)) = input.iter().enumerate().find(|(_, line)| !line.chars().all(|c| Rock::from(c).is_some())) {
+            return Err(Error::Parse { line: i + 1, text: line.clone(), message: "expected '.', '#' or 'O'".to_string() });
+        }
+        let rocks = Grid::from_lines(input, |c| Rock::from(c).unwrap())?;
+        Ok(Map { rocks })
+    }
+
+    /* rolls every round rock north until it hits a cube rock, another round
+       rock or the edge */
+    fn tilt(&mut self) {
+        for x in 0 .. self.rocks.width() as isize {
+            let mut free = 0;
+            for y in 0 .. self.rocks.height() as isize {
+                let pos = Position { x, y };
+                match self.rocks[pos] {
+                    Rock::Cube => free = y + 1,
+                    Rock::Round => {
+                        self.rocks[pos] = Rock::Empty;
+                        self.rocks[Position { x, y: free }] = Rock::Round;
+                        free += 1;
+                    },
+                    Rock::Empty => {},
+                }
+            }
+        }
+    }
+
+    fn rotate_right(&self) -> Grid<Rock> {
+        let height = self.rocks.height() as isize;
+        let mut rotated = Grid::new(self.rocks.height(), self.rocks.width(), Rock::Empty);
+        for (pos, &rock) in self.rocks.iter() {
+            rotated[Position { x: height - pos.y - 1, y: pos.x }] = rock;
+        }
+        rotated
+    }
+
+    fn cycle(&mut self) {
+        for _ in 0 .. 4 {
+            self.tilt();
+            self.rocks = self.rotate_right();
+        }
+    }
+
+    fn cycles(&self, amount: usize) -> Map {
+        let next = |map: &Map| {
+            let mut map = map.clone();
+            map.cycle();
+            map
+        };
+        cycle::state_at(self.clone(), next, amount)
+    }
+
+    fn load(&self) -> isize {
+        let height = self.rocks.height() as isize;
+        self.rocks.iter()
+                  .filter(|&(_, &rock)| rock == Rock::Round)
+                  .map(|(pos, _)| height - pos.y)
+                  .sum()
+    }
+
+    fn _print_map(&self) {
+        println!("{}", self.rocks.render(|rock| match rock {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        }));
+    }
+}
+
+fn total_load(map: &Map) -> isize {
+    let mut map = map.clone();
+    map.tilt();
+    map.load()
+}
+
+fn total_load_cycles(map: &Map) -> isize {
+    map.cycles(1_000_000_000).load()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "O....#....",
+            "O.OO#....#",
+            ".....##...",
+            "OO.#O....O",
+            ".O.....O#.",
+            "O.#..O.#.#",
+            "..O..#O..O",
+            ".......O..",
+            "#....###..",
+            "#OO..#....",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let map = Day14::parse(&input.join("\n")).unwrap();
+        assert_eq!(total_load(&map), 136);
+        assert_eq!(total_load_cycles(&map), 64);
+
+        assert!(Day14::parse("O.#\n.o.").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use crate::{Error, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let input = input.trim_end();
+        if input.contains('\n') {
+            return Err(Error::Invalid("expected the sequence on a single line".to_string()));
+        }
+        input.split(',')
+             .map(|text| Operation::from(text).map(|operation| Step { text: text.to_string(), operation })
+                                              .ok_or_else(|| Error::Invalid(format!("invalid step {:?}", text))))
+             .collect()
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(hash_sum(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(focusing_power(input))
+    }
+}
+
+fn hash(input: &str) -> usize {
+    let mut value = 0;
+
+    for c in input.chars() {
+        value += c as usize;
+        value *= 17;
+        value %= 256;
+    }
+
+    value
+}
+
+#[derive(Clone, Debug)]
+struct Lens {
+    label: String,
+    focal_length: usize,
+}
+
+/* a step of the initialization sequence, as written and as decoded */
+pub struct Step {
+    text: String,
+    operation: Operation,
+}
+
+enum Operation {
+    Assign(Lens),
+    Remove(String),
+}
+
+impl Operation {
+    fn from(input: &str) -> Option<Operation> {
+        if let Some((label, value)) = input.split_once('=') {
+            Some(Operation::Assign(Lens {
+                label: Operation::parse_label(label)?,
+                focal_length: value.parse().ok()?,
+            }))
+        } else {
+            Some(Operation::Remove(Operation::parse_label(input.strip_suffix('-')?)?))
+        }
+    }
+
+    fn parse_label(label: &str) -> Option<String> {
+        (!label.is_empty() && label.chars().all(|c| c.is_ascii_lowercase())).then(|| label.to_string())
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Operation::Assign(lens) => lens.label.clone(),
+            Operation::Remove(label) => label.to_string(),
+        }
+    }
+}
+
+fn hash_sum(steps: &[Step]) -> usize {
+    steps.iter()
+         .map(|step| hash(&step.text))
+         .sum()
+}
+
+fn init_sequence(boxes: &mut [VecDeque::<Lens>], steps: &[Step]) {
+    for operation in steps.iter().map(|step| &step.operation) {
+        let box_nr = hash(&operation.label());
+        match operation {
+            Operation::Assign(lens) => {
+                if let Some(boxed_lens) = boxes[box_nr].iter_mut().find(|l| l.label == lens.label) {
+                    boxed_lens.focal_length = lens.focal_length;
+                } else {
+                    boxes[box_nr].push_back(lens.clone());
+                }
+            },
+            Operation::Remove(label) => {
+                boxes[box_nr].retain(|lens| lens.label != *label);
+            }
+        }
+    }
+}
+
+fn focusing_power(steps: &[Step]) -> usize {
+    let mut boxes = vec![VecDeque::<Lens>::new(); 256];
+    init_sequence(&mut boxes, steps);
+
+    boxes.iter()
+         .enumerate()
+         .map(|(box_nr, lenses)| lenses.iter().enumerate().map(|(slot, lens)| (box_nr + 1) * (slot + 1) * lens.focal_length).sum::<usize>())
+         .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let steps = Day15::parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n").unwrap();
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash_sum(&steps), 1320);
+        assert_eq!(focusing_power(&steps), 145);
+
+        assert!(Day15::parse("rn=1,cm").is_err());
+        assert!(Day15::parse("rn=x").is_err());
+        assert!(Day15::parse("rn=1\ncm-").is_err());
+    }
+}
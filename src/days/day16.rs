@@ -1,19 +1,25 @@
 use std::collections::HashMap;
+use std::fmt::Display;
+use crate::geometry::{Direction, Position};
+use crate::{Error, Grid, Solution};
 
-static DAY: u8 = 16;
+pub struct Day16;
 
-fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", energized_tiles(&input));
-    println!("{DAY}b: {}", most_energized_tiles(&input));
-}
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Map;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Map::new(&crate::lines(input))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(energized_tiles(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(most_energized_tiles(input))
+    }
 }
 
 #[derive(Clone)]
@@ -26,16 +32,16 @@ impl Mirror {
     fn next_direction(&self, direction: Direction) -> Direction {
         match self {
             Mirror::Backward => match direction {
-                Direction::Up => Direction::Left,
-                Direction::Down => Direction::Right,
-                Direction::Left => Direction::Up,
-                Direction::Right => Direction::Down,
+                Direction::North => Direction::West,
+                Direction::South => Direction::East,
+                Direction::West => Direction::North,
+                Direction::East => Direction::South,
             },
             Mirror::Forward => match direction {
-                Direction::Up => Direction::Right,
-                Direction::Down => Direction::Left,
-                Direction::Left => Direction::Down,
-                Direction::Right => Direction::Up,
+                Direction::North => Direction::East,
+                Direction::South => Direction::West,
+                Direction::West => Direction::South,
+                Direction::East => Direction::North,
             },
         }
     }
@@ -52,16 +58,16 @@ impl Splitter {
         let mut directions = Vec::new();
         match self {
             Splitter::Horizontal => match direction {
-                Direction::Up => { directions.push(Direction::Left); directions.push(Direction::Right); },
-                Direction::Down => { directions.push(Direction::Left); directions.push(Direction::Right); },
-                Direction::Left => { directions.push(direction); },
-                Direction::Right => { directions.push(direction); },
+                Direction::North => { directions.push(Direction::West); directions.push(Direction::East); },
+                Direction::South => { directions.push(Direction::West); directions.push(Direction::East); },
+                Direction::West => { directions.push(direction); },
+                Direction::East => { directions.push(direction); },
             },
             Splitter::Vertical => match direction {
-                Direction::Up => { directions.push(direction); },
-                Direction::Down => { directions.push(direction); },
-                Direction::Left => { directions.push(Direction::Up); directions.push(Direction::Down); },
-                Direction::Right => { directions.push(Direction::Up); directions.push(Direction::Down); },
+                Direction::North => { directions.push(direction); },
+                Direction::South => { directions.push(direction); },
+                Direction::West => { directions.push(Direction::North); directions.push(Direction::South); },
+                Direction::East => { directions.push(Direction::North); directions.push(Direction::South); },
             },
         }
         directions
@@ -92,52 +98,23 @@ impl Object {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct Position {
-    x: isize,
-    y: isize,
-}
-
-impl Position {
-    fn next_pos(&self, direction: Direction) -> Position {
-        match direction {
-            Direction::Up => Position { x: self.x, y: self.y - 1 },
-            Direction::Down => Position { x: self.x, y: self.y + 1 },
-            Direction::Left => Position { x: self.x - 1, y: self.y },
-            Direction::Right => Position { x: self.x + 1, y: self.y },
-        }
-    }
-}
-
 #[derive(Clone)]
-struct Map {
-    map: HashMap<Position, Object>,
+pub struct Map {
+    map: Grid<Option<Object>>,
     energized: HashMap<Position, Vec<Direction>>,
-    width: isize,
-    height: isize,
 }
 
 impl Map {
-    fn new(input: &[String]) -> Map {
-        let mut map = HashMap::new();
-        for (y, line) in input.iter().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                let pos = Position { x: x as isize, y: y as isize };
-                if let Some(obj) = Object::from(c) {
-                    map.insert(pos, obj);
-                }
-            }
-        }
-        Map {
-            map,
-            energized: HashMap::new(),
-            width: input[0].len() as isize,
-            height: input.len() as isize,
+    fn new(input: &[String]) -> Result<Map, Error> {
+        if let Some((i, line)) = input.iter().enumerate().find(|(_, line)| !line.chars().all(|c| c == '.' || Object::from(c).is_some())) {
+            return Err(Error::Parse { line: i + 1, text: line.clone(), message: "expected '.', mirrors or splitters".to_string() });
         }
+        let map = Grid::from_lines(input, Object::from)?;
+        Ok(Map { map, energized: HashMap::new() })
     }
 
     fn outside_map(&self, pos: Position) -> bool {
-        pos.x < 0 || pos.x >= self.width || pos.y < 0 || pos.y >= self.height
+        !self.map.contains(pos)
     }
 
     fn energize(&mut self, beam: Beam) {
@@ -157,21 +134,21 @@ impl Map {
                 }
 
                 let mut beam = beam;
-                if let Some(obj) = self.map.get(&beam.pos) {
+                if let Some(obj) = &self.map[beam.pos] {
                     match obj {
                         Object::Mirror(mirror) => {
                             beam.direction = mirror.next_direction(beam.direction);
-                            beam.pos = beam.pos.next_pos(beam.direction);
+                            beam.pos = beam.pos.step(beam.direction);
                             beam_heads.push(beam);
                         },
                         Object::Splitter(splitter) => {
                             for direction in splitter.new_directions(beam.direction) {
-                                beam_heads.push(Beam { pos: beam.pos.next_pos(direction), direction });
+                                beam_heads.push(Beam { pos: beam.pos.step(direction), direction });
                             }
                         },
                     }
                 } else {
-                    beam.pos = beam.pos.next_pos(beam.direction);
+                    beam.pos = beam.pos.step(beam.direction);
                     beam_heads.push(beam);
                 }
             }
@@ -184,15 +161,15 @@ impl Map {
     }
 
     fn _print_map(&self) {
-        for y in 0 .. self.height {
-            for x in 0 .. self.width {
+        for y in 0 .. self.map.height() as isize {
+            for x in 0 .. self.map.width() as isize {
                 if let Some(directions) = self.energized.get(&Position { x, y }) {
                     if directions.len() == 1 {
                         match directions[0] {
-                            Direction::Up => print!("^"),
-                            Direction::Down => print!("v"),
-                            Direction::Left => print!("<"),
-                            Direction::Right => print!(">"),
+                            Direction::North => print!("^"),
+                            Direction::South => print!("v"),
+                            Direction::West => print!("<"),
+                            Direction::East => print!(">"),
                         }
                     } else {
                         print!("{}", directions.len());
@@ -206,19 +183,18 @@ impl Map {
     }
 }
 
-fn energized_tiles(input: &[String]) -> usize {
-    let mut map = Map::new(input);
-    map.energize(Beam { pos: Position { x: 0, y: 0 }, direction: Direction::Right });
+fn energized_tiles(map: &Map) -> usize {
+    let mut map = map.clone();
+    map.energize(Beam { pos: Position { x: 0, y: 0 }, direction: Direction::East });
     map.tiles_energized()
 }
 
-fn most_energized_tiles(input: &[String]) -> usize {
-    let map = Map::new(input);
-
-    let top = (0 .. map.width).map(|x| Beam { pos: Position { x, y: 0 }, direction: Direction::Down });
-    let bottom = (0 .. map.width).map(|x| Beam { pos: Position { x, y: map.height - 1 }, direction: Direction::Up });
-    let left = (0 .. map.height).map(|y| Beam { pos: Position { x: 0, y }, direction: Direction::Right });
-    let right = (0 .. map.height).map(|y| Beam { pos: Position { x: map.width - 1, y }, direction: Direction::Left });
+fn most_energized_tiles(map: &Map) -> usize {
+    let (width, height) = (map.map.width() as isize, map.map.height() as isize);
+    let top = (0 .. width).map(|x| Beam { pos: Position { x, y: 0 }, direction: Direction::South });
+    let bottom = (0 .. width).map(|x| Beam { pos: Position { x, y: height - 1 }, direction: Direction::North });
+    let left = (0 .. height).map(|y| Beam { pos: Position { x: 0, y }, direction: Direction::East });
+    let right = (0 .. height).map(|y| Beam { pos: Position { x: width - 1, y }, direction: Direction::West });
 
     let mut most_energized = 0;
     for beam in top.chain(bottom).chain(left).chain(right) {
@@ -247,7 +223,11 @@ mod tests {
             r".|....-|.\",
             r"..//.|....",
         ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
-        assert_eq!(energized_tiles(&input), 46);
-        assert_eq!(most_energized_tiles(&input), 51);
+        let map = Day16::parse(&input.join("\n")).unwrap();
+        assert_eq!(energized_tiles(&map), 46);
+        assert_eq!(most_energized_tiles(&map), 51);
+
+        assert!(Day16::parse(".|.\n.x.").is_err());
+        assert!(Day16::parse(".|.\n..").is_err());
     }
 }
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+use crate::geometry::{Direction, Position};
+use crate::search::dijkstra;
+use crate::{Error, Grid, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let input = crate::lines(input);
+        if let Some((i, line)) = input.iter().enumerate().find(|(_, line)| !line.chars().all(|c| c.is_ascii_digit())) {
+            return Err(Error::Parse { line: i + 1, text: line.clone(), message: "expected digits".to_string() });
+        }
+        Grid::from_lines(&input, |c| c as u8 - b'0')
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        minimum_heat_loss(input, Crucible::NORMAL).ok_or_else(|| Error::Invalid("no path to the factory".to_string()))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        minimum_heat_loss(input, Crucible::ULTRA).ok_or_else(|| Error::Invalid("no path to the factory".to_string()))
+    }
+}
+
+/* number of blocks the crucible has to move straight before it may turn or
+   stop, and after which it has to turn */
+#[derive(Clone, Copy, Debug)]
+pub struct Crucible {
+    pub min_straight: usize,
+    pub max_straight: usize,
+}
+
+impl Crucible {
+    pub const NORMAL: Crucible = Crucible { min_straight: 1, max_straight: 3 };
+    pub const ULTRA: Crucible = Crucible { min_straight: 4, max_straight: 10 };
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct State {
+    position: Position,
+    direction: Direction,
+    straight: usize,
+}
+
+/* None if the crucible can't reach the bottom right corner */
+pub fn minimum_heat_loss(map: &Grid<u8>, crucible: Crucible) -> Option<u64> {
+    if map.width() == 0 || map.height() == 0 {
+        return None;
+    }
+    let start = Position::new(0, 0);
+    let goal = Position::new(map.width() as isize - 1, map.height() as isize - 1);
+    if start == goal {
+        /* already at the factory, without moving at all */
+        return Some(0);
+    }
+    let starts = [Direction::East, Direction::South].map(|direction| State { position: start, direction, straight: 0 });
+
+    let neighbors = |state: &State| {
+        let mut directions = Vec::with_capacity(3);
+        if state.straight < crucible.max_straight {
+            directions.push(state.direction);
+        }
+        if state.straight >= crucible.min_straight {
+            directions.extend([state.direction.turn_left(), state.direction.turn_right()]);
+        }
+        directions.into_iter()
+                  .map(|direction| {
+                      let straight = if direction == state.direction { state.straight + 1 } else { 1 };
+                      State { position: state.position.step(direction), direction, straight }
+                  })
+                  .filter_map(|next| map.get(next.position).map(|&loss| (next, loss as u64)))
+                  .collect::<Vec<_>>()
+    };
+    let is_goal = |state: &State| state.position == goal && state.straight >= crucible.min_straight;
+
+    dijkstra(starts, neighbors, is_goal).map(|(_, heat_loss)| heat_loss)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "2413432311323",
+            "3215453535623",
+            "3255245654254",
+            "3446585845452",
+            "4546657867536",
+            "1438598798454",
+            "4457876987766",
+            "3637877979653",
+            "4654967986887",
+            "4564679986453",
+            "1224686865563",
+            "2546548887735",
+            "4322674655533",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let map = Day17::parse(&input.join("\n")).unwrap();
+        assert_eq!(minimum_heat_loss(&map, Crucible::NORMAL), Some(102));
+        assert_eq!(minimum_heat_loss(&map, Crucible::ULTRA), Some(94));
+
+        let input = [
+            "111111111111",
+            "999999999991",
+            "999999999991",
+            "999999999991",
+            "999999999991",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let map = Day17::parse(&input.join("\n")).unwrap();
+        assert_eq!(minimum_heat_loss(&map, Crucible::ULTRA), Some(71));
+
+        /* the ultra crucible can't turn within a 3x3 map */
+        let map = Day17::parse("111\n111\n111").unwrap();
+        assert_eq!(minimum_heat_loss(&map, Crucible::NORMAL), Some(4));
+        assert_eq!(minimum_heat_loss(&map, Crucible::ULTRA), None);
+
+        let map = Day17::parse("5").unwrap();
+        assert_eq!(minimum_heat_loss(&map, Crucible::NORMAL), Some(0));
+        assert_eq!(minimum_heat_loss(&map, Crucible::ULTRA), Some(0));
+
+        let map = Day17::parse("111\n111\n111").unwrap();
+        assert!(Day17::part_b(&map).is_err());
+
+        assert!(Day17::parse("12\n3x").is_err());
+    }
+}
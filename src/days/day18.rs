@@ -0,0 +1,138 @@
+use std::fmt::Display;
+use crate::geometry::{self, Direction, Position};
+use crate::{Error, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let plan = crate::lines(input).iter()
+                                      .enumerate()
+                                      .map(|(i, line)| Instruction::new(line).ok_or_else(|| Error::Parse {
+                                          line: i + 1,
+                                          text: line.clone(),
+                                          message: "expected direction, distance and color".to_string(),
+                                      }))
+                                      .collect::<Result<Vec<_>, _>>()?;
+        if plan.is_empty() {
+            return Err(Error::Invalid("empty dig plan".to_string()));
+        }
+        Ok(plan)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        lagoon_volume(input.iter().map(|instruction| instruction.step))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        lagoon_volume(input.iter().map(|instruction| instruction.color_step))
+    }
+}
+
+/* the plan as written, and as decoded from the color code: five hex digits of
+   distance followed by the direction */
+pub struct Instruction {
+    step: (Direction, isize),
+    color_step: (Direction, isize),
+}
+
+impl Instruction {
+    fn new(input: &str) -> Option<Instruction> {
+        let mut tokens = input.split(' ');
+        let direction = match tokens.next()? {
+            "U" => Direction::North,
+            "R" => Direction::East,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            _ => return None,
+        };
+        let distance = isize::try_from(tokens.next()?.parse::<usize>().ok()?).ok()?;
+        let color = tokens.next()?.strip_prefix("(#")?.strip_suffix(')')?;
+        if color.len() != 6 || tokens.next().is_some() {
+            return None;
+        }
+
+        let color_distance = isize::from_str_radix(&color[..5], 16).ok()?;
+        let color_direction = match &color[5..] {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            _ => return None,
+        };
+        Some(Instruction { step: (direction, distance), color_step: (color_direction, color_distance) })
+    }
+}
+
+/* the trench is dug one cube wide around the lagoon, so its cubes count as
+   well as the ones enclosed: by Pick's theorem that is A + b/2 + 1, which
+   also holds for trenches that enclose nothing; the trench only runs along
+   the axes, so b is its length */
+fn lagoon_volume(steps: impl Iterator<Item = (Direction, isize)>) -> Result<u64, Error> {
+    let too_large = || Error::Invalid("lagoon too large".to_string());
+    let mut position = Position::default();
+    let mut corners = Vec::new();
+    let mut trench: u64 = 0;
+    for (direction, distance) in steps {
+        corners.push(position);
+        position = position.checked_step_by(direction, distance).ok_or_else(too_large)?;
+        trench = trench.checked_add(distance as u64).ok_or_else(too_large)?;
+    }
+    if position != Position::default() {
+        return Err(Error::Invalid("dig plan doesn't return to its start".to_string()));
+    }
+    let double_area = geometry::double_area(&corners).ok_or_else(too_large)?;
+    double_area.checked_add(trench)
+               .map(|cubes| cubes / 2 + 1)
+               .ok_or_else(too_large)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "R 6 (#70c710)",
+            "D 5 (#0dc571)",
+            "L 2 (#5713f0)",
+            "D 2 (#d2c081)",
+            "R 2 (#59c680)",
+            "D 2 (#411b91)",
+            "L 5 (#8ceee2)",
+            "U 2 (#caa173)",
+            "L 1 (#1b58a2)",
+            "U 2 (#caa171)",
+            "R 2 (#7807d2)",
+            "U 3 (#a77fa3)",
+            "L 2 (#015232)",
+            "U 2 (#7a21e3)",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let plan = Day18::parse(&input.join("\n")).unwrap();
+        assert_eq!(plan[0].color_step, (Direction::East, 461937));
+        assert_eq!(Day18::part_a(&plan).unwrap().to_string(), "62");
+        assert_eq!(Day18::part_b(&plan).unwrap().to_string(), "952408144115");
+
+        assert!(Day18::parse("X 6 (#70c710)").is_err());
+        assert!(Day18::parse("R 6 (#70c714)").is_err());
+        assert!(Day18::parse("R 6").is_err());
+        assert!(Day18::parse("").is_err());
+
+        /* a trench dug there and back again */
+        let plan = Day18::parse("R 5 (#000050)\nL 5 (#000052)").unwrap();
+        assert_eq!(Day18::part_a(&plan).unwrap().to_string(), "6");
+        assert_eq!(Day18::part_b(&plan).unwrap().to_string(), "6");
+
+        let plan = Day18::parse("R 5 (#000050)\nD 5 (#000051)").unwrap();
+        assert!(Day18::part_a(&plan).is_err());
+
+        assert!(Day18::parse("R -5 (#000050)\nR 5 (#000052)").is_err());
+        let far = isize::MAX;
+        let plan = Day18::parse(&format!("R {} (#000050)\nR {} (#000050)\nL {} (#000050)", far, far, far)).unwrap();
+        assert!(Day18::part_a(&plan).is_err());
+    }
+}
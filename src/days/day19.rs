@@ -0,0 +1,809 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use crate::interval::{Interval, RangeSet};
+use crate::{Error, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let input = crate::lines(input);
+        let blocks = input.split(|line| line.is_empty()).collect::<Vec<_>>();
+        let mut workflows = Workflows::new(blocks[0])?;
+        let (errors, warnings): (Vec<_>, Vec<_>) = workflows.validate().into_iter().partition(ValidationError::is_fatal);
+        if !errors.is_empty() {
+            let errors = errors.iter().map(|err| err.to_string()).collect::<Vec<_>>();
+            return Err(Error::Invalid(errors.join("; ")));
+        }
+        let warnings = warnings.iter().map(|warning| warning.to_string()).collect();
+        workflows.simplify();
+        let tree = workflows.compile();
+        /* parts are numbered after the workflows and the empty line */
+        let parts: Vec<Part> = blocks.get(1)
+                          .ok_or_else(|| Error::Invalid("no parts after the workflows".to_string()))?
+                          .iter()
+                          .enumerate()
+                          .map(|(i, line)| Part::new(line).ok_or_else(|| Error::Parse {
+                              line: blocks[0].len() + i + 2,
+                              text: line.clone(),
+                              message: "expected \"{x=..,m=..}\"".to_string(),
+                          }))
+                          .collect::<Result<_, _>>()?;
+        let parts = parts.iter()
+                         .map(|part| ResolvedPart { values: tree.resolve(part), rating: part.rating() })
+                         .collect();
+        Ok(System { workflows, tree, parts, warnings })
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(rating_numbers(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(accepted_combinations(input))
+    }
+
+    fn warnings(input: &Self::Input) -> Vec<String> {
+        input.warnings.clone()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum WorkflowResult {
+    Accept,
+    Reject,
+    NextWorkflow(String),
+}
+
+impl WorkflowResult {
+    fn next_workflow(&self) -> Option<&str> {
+        match self {
+            WorkflowResult::NextWorkflow(next) => Some(next),
+            _ => None,
+        }
+    }
+
+    fn from(input: &str) -> Option<WorkflowResult> {
+        match input {
+            "A" => Some(WorkflowResult::Accept),
+            "R" => Some(WorkflowResult::Reject),
+            next => is_name(next).then(|| WorkflowResult::NextWorkflow(next.to_string())),
+        }
+    }
+}
+
+fn is_name(input: &str) -> bool {
+    !input.is_empty() && input.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+const DOMAIN: Interval = Interval { start: 0, end: u32::MAX as i64 + 1 };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Operator {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Operator {
+    fn from(input: &str) -> Option<Operator> {
+        match input {
+            "<" => Some(Operator::Lt),
+            "<=" => Some(Operator::Le),
+            ">" => Some(Operator::Gt),
+            ">=" => Some(Operator::Ge),
+            "==" => Some(Operator::Eq),
+            "!=" => Some(Operator::Ne),
+            _ => None,
+        }
+    }
+
+    fn apply(&self, left: u32, right: u32) -> bool {
+        match self {
+            Operator::Lt => left < right,
+            Operator::Le => left <= right,
+            Operator::Gt => left > right,
+            Operator::Ge => left >= right,
+            Operator::Eq => left == right,
+            Operator::Ne => left != right,
+        }
+    }
+
+    /* all values v for which `v op right` holds */
+    fn values(&self, right: u32) -> RangeSet {
+        let right = right as i64;
+        let interval = match self {
+            Operator::Lt => Interval::new(DOMAIN.start, right),
+            Operator::Le => Interval::new(DOMAIN.start, right + 1),
+            Operator::Gt => Interval::new(right + 1, DOMAIN.end),
+            Operator::Ge => Interval::new(right, DOMAIN.end),
+            Operator::Eq => Interval::with_len(right, 1),
+            Operator::Ne => return DOMAIN.difference(&Interval::with_len(right, 1)),
+        };
+        RangeSet::from([interval])
+    }
+}
+
+#[derive(Clone)]
+struct Condition {
+    attribute: String,
+    operator: Operator,
+    value: u32,
+}
+
+impl Condition {
+    fn new(input: &str) -> Option<Condition> {
+        let start = input.find(['<', '>', '=', '!'])?;
+        let end = start + input[start..].find(|c: char| c.is_ascii_digit())?;
+        let attribute = Some(&input[..start]).filter(|attribute| is_name(attribute))?.to_string();
+        let operator = Operator::from(&input[start..end])?;
+        let value = input[end..].parse().ok()?;
+        Some(Condition { attribute, operator, value })
+    }
+
+    #[cfg(test)]
+    fn matches(&self, part: &Part) -> bool {
+        self.operator.apply(part.get(&self.attribute), self.value)
+    }
+}
+
+#[derive(Clone)]
+enum Rule {
+    Conditional(Condition, WorkflowResult),
+    Result(WorkflowResult),
+}
+
+impl Rule {
+    fn new(input: &str) -> Option<Rule> {
+        match input.split_once(':') {
+            Some((condition, result)) => Some(Rule::Conditional(Condition::new(condition)?, WorkflowResult::from(result)?)),
+            None => Some(Rule::Result(WorkflowResult::from(input)?)),
+        }
+    }
+
+    fn result(&self) -> &WorkflowResult {
+        match self {
+            Rule::Conditional(_, result) | Rule::Result(result) => result,
+        }
+    }
+
+    fn result_mut(&mut self) -> &mut WorkflowResult {
+        match self {
+            Rule::Conditional(_, result) | Rule::Result(result) => result,
+        }
+    }
+
+    #[cfg(test)]
+    fn matches(&self, part: &Part) -> Option<WorkflowResult> {
+        match self {
+            Rule::Conditional(condition, result) => condition.matches(part).then(|| result.clone()),
+            Rule::Result(result) => Some(result.clone()),
+        }
+    }
+
+    /* splits the ranges into the part matching this rule and the rest */
+    fn split(&self, ranges: PartRanges) -> (Option<(PartRanges, WorkflowResult)>, Option<PartRanges>) {
+        match self {
+            Rule::Conditional(condition, result) => {
+                let (matching, rest) = ranges.split(condition);
+                (matching.map(|ranges| (ranges, result.clone())), rest)
+            },
+            Rule::Result(result) => (Some((ranges, result.clone())), None),
+        }
+    }
+}
+
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn new(input: &str) -> Option<Workflow> {
+        let (name, rules_str) = input.strip_suffix('}')?.split_once('{')?;
+        let rules = rules_str.split(',')
+                             .map(Rule::new)
+                             .collect::<Option<Vec<_>>>()?;
+        let name = Some(name).filter(|name| is_name(name))?.to_string();
+
+        Some(Workflow { name, rules })
+    }
+
+    fn targets(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().filter_map(|rule| rule.result().next_workflow())
+    }
+
+    fn attributes(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().filter_map(|rule| match rule {
+            Rule::Conditional(condition, _) => Some(condition.attribute.as_str()),
+            Rule::Result(_) => None,
+        })
+    }
+
+    /* drops rules that can never match or don't change the outcome; returns
+       whether anything was removed */
+    fn simplify(&mut self) -> bool {
+        let before = self.rules.len();
+        let mut remaining = PartRanges::new(self.attributes(), DOMAIN);
+        let mut rules = Vec::with_capacity(before);
+        for rule in self.rules.drain(..) {
+            match rule.split(remaining.clone()) {
+                /* shadowed by earlier rules */
+                (None, Some(rest)) => remaining = rest,
+                /* matches everything that is left */
+                (Some((_, result)), None) => { rules.push(Rule::Result(result)); break; },
+                (Some(_), Some(rest)) => { rules.push(rule); remaining = rest; },
+                (None, None) => unreachable!(),
+            }
+        }
+        /* a condition with the same result as the fallback is redundant */
+        while rules.len() >= 2 && rules[rules.len() - 2].result() == rules[rules.len() - 1].result() {
+            rules.remove(rules.len() - 2);
+        }
+        self.rules = rules;
+        self.rules.len() != before
+    }
+
+    #[cfg(test)]
+    fn matches(&self, part: &Part) -> WorkflowResult {
+        for rule in &self.rules {
+            if let Some(result) = rule.matches(part) {
+                return result.clone();
+            }
+        }
+        panic!("no matching rule found");
+    }
+}
+
+/* named attribute values; attributes a part doesn't list are 0 */
+struct Part {
+    attributes: HashMap<String, u32>,
+}
+
+impl Part {
+    fn new(input: &str) -> Option<Part> {
+        let attributes = input.strip_prefix('{')?
+                              .strip_suffix('}')?
+                              .split(',')
+                              .filter(|string| !string.is_empty())
+                              .map(|string| {
+                                  let (attribute, value) = string.split_once('=')?;
+                                  Some((attribute.to_string(), value.parse().ok()?))
+                              })
+                              .collect::<Option<_>>()?;
+        Some(Part { attributes })
+    }
+
+    fn get(&self, attribute: &str) -> u32 {
+        self.attributes.get(attribute).copied().unwrap_or(0)
+    }
+
+    fn rating(&self) -> u64 {
+        self.attributes.values().map(|&value| value as u64).sum()
+    }
+}
+
+/* possible values per attribute; like for parts, attributes that aren't
+   tracked are 0 */
+#[derive(Clone)]
+struct PartRanges {
+    ranges: HashMap<String, RangeSet>,
+}
+
+impl PartRanges {
+    fn new<'a>(attributes: impl IntoIterator<Item = &'a str>, interval: Interval) -> PartRanges {
+        let ranges = attributes.into_iter()
+                               .map(|attribute| (attribute.to_string(), RangeSet::from([interval])))
+                               .collect();
+        PartRanges { ranges }
+    }
+
+    #[cfg(test)]
+    fn with(mut self, attribute: &str, interval: Interval) -> PartRanges {
+        self.ranges.insert(attribute.to_string(), RangeSet::from([interval]));
+        self
+    }
+
+    fn get(&self, attribute: &str) -> RangeSet {
+        match self.ranges.get(attribute) {
+            Some(ranges) => ranges.clone(),
+            None => RangeSet::from([Interval::with_len(0, 1)]),
+        }
+    }
+
+    fn split(&self, condition: &Condition) -> (Option<PartRanges>, Option<PartRanges>) {
+        let values = condition.operator.values(condition.value);
+        let current = self.get(&condition.attribute);
+        let with = |ranges: RangeSet| {
+            let mut part = self.clone();
+            part.ranges.insert(condition.attribute.clone(), ranges);
+            part
+        };
+        let matching = Some(current.intersection(&values)).filter(|r| !r.is_empty()).map(with);
+        let rest = Some(current.difference(&values)).filter(|r| !r.is_empty()).map(with);
+        (matching, rest)
+    }
+
+    fn combinations(&self) -> u64 {
+        self.ranges.values()
+                   .map(|ranges| ranges.len() as u64)
+                   .product()
+    }
+}
+
+/* comparison nodes hold the attribute index, the comparison and the nodes to
+   continue with when the comparison holds or not */
+enum Node {
+    Accept,
+    Reject,
+    Compare(usize, Operator, u32, usize, usize),
+}
+
+/* comparisons refer to attributes by index; parts are resolved to their
+   values in that order once, so evaluating them needs no lookups by name */
+struct DecisionTree {
+    nodes: Vec<Node>,
+    root: usize,
+    attributes: Vec<String>,
+}
+
+impl DecisionTree {
+    fn attribute(&mut self, name: &str) -> usize {
+        match self.attributes.iter().position(|attribute| attribute == name) {
+            Some(index) => index,
+            None => { self.attributes.push(name.to_string()); self.attributes.len() - 1 },
+        }
+    }
+
+    fn resolve(&self, part: &Part) -> Vec<u32> {
+        self.attributes.iter()
+                       .map(|attribute| part.get(attribute))
+                       .collect()
+    }
+
+    fn is_accepted(&self, values: &[u32]) -> bool {
+        let mut node = self.root;
+        loop {
+            node = match self.nodes[node] {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Compare(attribute, operator, value, matched, unmatched) =>
+                    if operator.apply(values[attribute], value) { matched } else { unmatched },
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ValidationError {
+    MissingStart,
+    UndefinedWorkflow { from: String, target: String },
+    Unreachable(String),
+    Cycle(Vec<String>),
+    MissingFinalRule(String),
+}
+
+impl ValidationError {
+    /* unreachable workflows are never evaluated, so they don't matter */
+    fn is_fatal(&self) -> bool {
+        !matches!(self, ValidationError::Unreachable(_))
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::MissingStart => write!(f, "no workflow named \"in\""),
+            ValidationError::UndefinedWorkflow { from, target } =>
+                write!(f, "workflow {} refers to undefined workflow {}", from, target),
+            ValidationError::Unreachable(name) => write!(f, "workflow {} is unreachable", name),
+            ValidationError::Cycle(names) => write!(f, "workflows loop: {} -> {}", names.join(" -> "), names[0]),
+            ValidationError::MissingFinalRule(name) =>
+                write!(f, "workflow {} doesn't end with an unconditional rule", name),
+        }
+    }
+}
+
+struct Workflows {
+    workflows: HashMap<String, Workflow>,
+}
+
+impl Workflows {
+    fn new(input: &[String]) -> Result<Workflows, Error> {
+        let mut workflows = HashMap::new();
+        for (i, line) in input.iter().enumerate() {
+            let error = |message: &str| Error::Parse { line: i + 1, text: line.clone(), message: message.to_string() };
+            let workflow = Workflow::new(line).ok_or_else(|| error("expected \"name{rules}\""))?;
+            if workflows.contains_key(&workflow.name) {
+                return Err(error("workflow defined twice"));
+            }
+            workflows.insert(workflow.name.clone(), workflow);
+        }
+        Ok(Workflows { workflows })
+    }
+
+    fn sorted_names(&self) -> Vec<&String> {
+        let mut names = self.workflows.keys().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if !self.workflows.contains_key("in") {
+            errors.push(ValidationError::MissingStart);
+        }
+
+        for name in self.sorted_names() {
+            let workflow = &self.workflows[name];
+            if !matches!(workflow.rules.last(), Some(Rule::Result(_))) {
+                errors.push(ValidationError::MissingFinalRule(name.clone()));
+            }
+            for target in workflow.targets() {
+                if !self.workflows.contains_key(target) {
+                    errors.push(ValidationError::UndefinedWorkflow { from: name.clone(), target: target.to_string() });
+                }
+            }
+        }
+
+        if self.workflows.contains_key("in") {
+            let mut reachable = HashSet::from(["in"]);
+            let mut queue = vec!["in"];
+            while let Some(name) = queue.pop() {
+                for target in self.workflows[name].targets() {
+                    if self.workflows.contains_key(target) && reachable.insert(target) {
+                        queue.push(target);
+                    }
+                }
+            }
+            for name in self.sorted_names() {
+                if !reachable.contains(name.as_str()) {
+                    errors.push(ValidationError::Unreachable(name.clone()));
+                }
+            }
+        }
+
+        let mut finished = HashSet::new();
+        for name in self.sorted_names() {
+            let mut path = Vec::new();
+            self.find_cycles(name, &mut path, &mut finished, &mut errors);
+        }
+        errors
+    }
+
+    /* depth-first search; a workflow already on the path closes a cycle */
+    fn find_cycles<'a>(&'a self, name: &'a str, path: &mut Vec<&'a str>,
+                       finished: &mut HashSet<&'a str>, errors: &mut Vec<ValidationError>) {
+        if finished.contains(name) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|&n| n == name) {
+            let cycle = path[start..].iter().map(|n| n.to_string()).collect();
+            errors.push(ValidationError::Cycle(cycle));
+            return;
+        }
+        let Some(workflow) = self.workflows.get(name) else { return };
+        path.push(name);
+        for target in workflow.targets() {
+            self.find_cycles(target, path, finished, errors);
+        }
+        path.pop();
+        finished.insert(name);
+    }
+
+    /* folds and inlines workflows until nothing changes; the outcome for any
+       part stays the same */
+    fn simplify(&mut self) {
+        loop {
+            let mut changed = false;
+            for workflow in self.workflows.values_mut() {
+                changed |= workflow.simplify();
+            }
+
+            /* workflows with a single unconditional rule are replaced by their result */
+            let trivial = self.workflows.values()
+                                        .filter(|workflow| workflow.name != "in" && workflow.rules.len() == 1)
+                                        .map(|workflow| (workflow.name.clone(), workflow.rules[0].result().clone()))
+                                        .collect::<HashMap<_, _>>();
+            for name in trivial.keys() {
+                self.workflows.remove(name);
+            }
+            for rule in self.workflows.values_mut().flat_map(|workflow| workflow.rules.iter_mut()) {
+                while let Some(result) = rule.result().next_workflow().and_then(|next| trivial.get(next)) {
+                    *rule.result_mut() = result.clone();
+                }
+            }
+            changed |= !trivial.is_empty();
+
+            /* a workflow only used as the fallback of another one is appended to it */
+            let mut uses = HashMap::new();
+            for target in self.workflows.values().flat_map(|workflow| workflow.targets()) {
+                *uses.entry(target.to_string()).or_insert(0) += 1;
+            }
+            let inlined = self.workflows.values().find_map(|workflow| {
+                let next = workflow.rules.last()?.result().next_workflow()?;
+                (next != "in" && next != workflow.name && uses[next] == 1).then(|| (workflow.name.clone(), next.to_string()))
+            });
+            if let Some((name, next)) = inlined {
+                let next = self.workflows.remove(&next).unwrap();
+                let workflow = self.workflows.get_mut(&name).unwrap();
+                workflow.rules.pop();
+                workflow.rules.extend(next.rules);
+                changed = true;
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    fn compile(&self) -> DecisionTree {
+        let mut tree = DecisionTree { nodes: vec![Node::Accept, Node::Reject], root: 0, attributes: Vec::new() };
+        let mut compiled = HashMap::new();
+        tree.root = self.compile_workflow("in", &mut tree, &mut compiled);
+        tree
+    }
+
+    /* workflows are compiled once and shared, so the tree is really a DAG */
+    fn compile_workflow<'a>(&'a self, name: &'a str, tree: &mut DecisionTree,
+                            compiled: &mut HashMap<&'a str, usize>) -> usize {
+        if let Some(&node) = compiled.get(name) {
+            return node;
+        }
+        let rules = &self.workflows[name].rules;
+        let (last, conditions) = rules.split_last().unwrap();
+        let mut node = self.compile_result(last.result(), tree, compiled);
+        for rule in conditions.iter().rev() {
+            let matched = self.compile_result(rule.result(), tree, compiled);
+            let Rule::Conditional(condition, _) = rule else {
+                panic!("unconditional rule before the end of workflow {}", name);
+            };
+            let attribute = tree.attribute(&condition.attribute);
+            tree.nodes.push(Node::Compare(attribute, condition.operator, condition.value, matched, node));
+            node = tree.nodes.len() - 1;
+        }
+        compiled.insert(name, node);
+        node
+    }
+
+    fn compile_result<'a>(&'a self, result: &'a WorkflowResult, tree: &mut DecisionTree,
+                          compiled: &mut HashMap<&'a str, usize>) -> usize {
+        match result {
+            WorkflowResult::Accept => 0,
+            WorkflowResult::Reject => 1,
+            WorkflowResult::NextWorkflow(next) => self.compile_workflow(next, tree, compiled),
+        }
+    }
+
+    /* interprets the workflows directly, as a reference for the compiled tree */
+    #[cfg(test)]
+    fn is_accepted(&self, part: &Part) -> bool {
+        let mut workflow = &self.workflows["in"];
+        loop {
+            match workflow.matches(part) {
+                WorkflowResult::Accept => return true,
+                WorkflowResult::Reject => return false,
+                WorkflowResult::NextWorkflow(next) => workflow = &self.workflows[&next],
+            }
+        }
+    }
+
+    fn accepted_combinations(&self, ranges: PartRanges) -> u64 {
+        let mut combinations = 0;
+        let mut queue = vec![(ranges, WorkflowResult::NextWorkflow("in".to_string()))];
+        while let Some((ranges, result)) = queue.pop() {
+            let workflow = match result {
+                WorkflowResult::Accept => { combinations += ranges.combinations(); continue; },
+                WorkflowResult::Reject => continue,
+                WorkflowResult::NextWorkflow(next) => &self.workflows[&next],
+            };
+            let mut rest = Some(ranges);
+            for rule in &workflow.rules {
+                let Some(ranges) = rest else { break };
+                let (matching, remaining) = rule.split(ranges);
+                queue.extend(matching);
+                rest = remaining;
+            }
+        }
+        combinations
+    }
+}
+
+/* a part's values in the order of the tree's attributes, and its rating */
+struct ResolvedPart {
+    values: Vec<u32>,
+    rating: u64,
+}
+
+/* the simplified workflows, compiled once for rating the parts, and the
+   problems found while validating them that don't affect the answers */
+pub struct System {
+    workflows: Workflows,
+    tree: DecisionTree,
+    parts: Vec<ResolvedPart>,
+    warnings: Vec<String>,
+}
+
+fn rating_numbers(system: &System) -> u64 {
+    system.parts.iter()
+                .filter(|part| system.tree.is_accepted(&part.values))
+                .map(|part| part.rating)
+                .sum()
+}
+
+fn accepted_combinations(system: &System) -> u64 {
+    system.workflows.accepted_combinations(PartRanges::new(["x", "m", "a", "s"], Interval::inclusive(1, 4000)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "px{a<2006:qkq,m>2090:A,rfg}",
+            "pv{a>1716:R,A}",
+            "lnx{m>1548:A,A}",
+            "rfg{s<537:gd,x>2440:R,A}",
+            "qs{s>3448:A,lnx}",
+            "qkq{x<1416:A,crn}",
+            "crn{x>2662:A,R}",
+            "in{s<1351:px,qqz}",
+            "qqz{s>2770:qs,m<1801:hdj,R}",
+            "gd{a>3333:R,R}",
+            "hdj{m>838:A,pv}",
+            "",
+            "{x=787,m=2655,a=1222,s=2876}",
+            "{x=1679,m=44,a=2067,s=496}",
+            "{x=2036,m=264,a=79,s=2244}",
+            "{x=2461,m=1339,a=466,s=291}",
+            "{x=2127,m=1623,a=2188,s=1013}",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let system = Day19::parse(&input.join("\n")).unwrap();
+        assert_eq!(rating_numbers(&system), 19114);
+        assert_eq!(accepted_combinations(&system), 167409079868000);
+        assert_eq!(Workflows::new(&input[..11]).unwrap().validate(), []);
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = [
+            "in{x<10:aa,bb}",
+            "aa{m>5:bb,cc}",
+            "bb{a<3:aa,R}",
+            "dd{A}",
+            "ee{s>1:A}",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        assert_eq!(Workflows::new(&input).unwrap().validate(), [
+            ValidationError::UndefinedWorkflow { from: "aa".to_string(), target: "cc".to_string() },
+            ValidationError::MissingFinalRule("ee".to_string()),
+            ValidationError::Unreachable("dd".to_string()),
+            ValidationError::Unreachable("ee".to_string()),
+            ValidationError::Cycle(vec!["aa".to_string(), "bb".to_string()]),
+        ]);
+
+        let input = ["px{A}".to_string()];
+        assert_eq!(Workflows::new(&input).unwrap().validate(), [ValidationError::MissingStart]);
+        assert!(Day19::parse("in{x<10:xx,A}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+        assert!(Day19::parse("in{x<10:A,R}\n").is_err());
+
+        /* an unreachable workflow is only a warning */
+        let system = Day19::parse("in{x<10:A,R}\nxx{A}\n\n{x=1,m=2,a=3,s=4}\n{x=10}\n").unwrap();
+        assert_eq!(rating_numbers(&system), 10);
+        assert_eq!(Day19::warnings(&system), ["workflow xx is unreachable"]);
+
+        /* ratings don't wrap around */
+        let system = Day19::parse("in{A}\n\n{a=4000000000,b=4000000000}\n").unwrap();
+        assert_eq!(rating_numbers(&system), 8000000000);
+    }
+
+    #[test]
+    fn test_parse() {
+        let line = |input: &str| match Day19::parse(input) {
+            Err(Error::Parse { line, .. }) => Some(line),
+            _ => None,
+        };
+        assert_eq!(line("in{x<1O:A,R}\n\n{x=1}"), Some(1));
+        assert_eq!(line("in{x<1:A,R}\nin{A}\n\n{x=1}"), Some(2));
+        assert_eq!(line("in{x<1:A,R}\n\nx=5"), Some(3));
+        assert_eq!(line("in{x<1:A,R}\n\n{x=1}\n{x=1,m}"), Some(4));
+        assert_eq!(line("in{x<>1:A,R}\n\n{x=1}"), Some(1));
+        assert_eq!(line("in{<1:A,R}\n\n{x=1}"), Some(1));
+        assert_eq!(line("in{x<1:,R}\n\n{x=1}"), Some(1));
+        assert_eq!(line("in{x<1:A,R\n\n{x=1}"), Some(1));
+    }
+
+    #[test]
+    fn test_simplify() {
+        let input = [
+            "in{s<1351:px,qqz}",
+            "px{a<2006:qkq,m>2090:A,rfg}",
+            "pv{a>1716:R,A}",
+            "lnx{m>1548:A,A}",
+            "rfg{s<537:gd,x>2440:R,A}",
+            "qs{s>3448:A,lnx}",
+            "qkq{x<1416:A,crn}",
+            "crn{x>2662:A,R}",
+            "qqz{s>2770:qs,m<1801:hdj,R}",
+            "gd{a>3333:R,R}",
+            "hdj{m>838:A,pv}",
+            "sh{x<100:A,x<50:R,x>99:A,R}",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let original = Workflows::new(&input[..11]).unwrap();
+        let mut workflows = Workflows::new(&input[..11]).unwrap();
+        workflows.simplify();
+        assert_eq!(workflows.validate(), []);
+        assert!(!workflows.workflows.contains_key("gd") && !workflows.workflows.contains_key("lnx"));
+        /* qs reduced to A, the other fallbacks appended to their only users */
+        assert_eq!(workflows.sorted_names(), ["hdj", "in", "px", "qkq"]);
+
+        let ranges = PartRanges::new(["x", "m", "a", "s"], Interval::inclusive(1, 4000));
+        assert_eq!(workflows.accepted_combinations(ranges.clone()), original.accepted_combinations(ranges));
+
+        let tree = workflows.compile();
+        for x in (1 .. 4000).step_by(211) {
+            for m in (1 .. 4000).step_by(197) {
+                for a in (1 .. 4000).step_by(283) {
+                    for s in (1 .. 4000).step_by(331) {
+                        let part = Part::new(&format!("{{x={},m={},a={},s={}}}", x, m, a, s)).unwrap();
+                        assert_eq!(tree.is_accepted(&tree.resolve(&part)), original.is_accepted(&part));
+                    }
+                }
+            }
+        }
+
+        /* the second rule is shadowed, the third always matches */
+        let mut workflow = Workflow::new(&input[11]).unwrap();
+        assert!(workflow.simplify());
+        assert_eq!(workflow.rules.iter().map(Rule::result).collect::<Vec<_>>(), [&WorkflowResult::Accept]);
+    }
+
+    #[test]
+    fn test_operators() {
+        let input = [
+            "in{weight>=100:heavy,color==3:R,route}",
+            "heavy{weight<=500:A,priority!=1:A,R}",
+            "route{priority<2:A,color>7:A,R}",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let mut workflows = Workflows::new(&input).unwrap();
+        assert_eq!(workflows.validate(), []);
+        let original = Workflows::new(&input).unwrap();
+        workflows.simplify();
+        let tree = workflows.compile();
+
+        let accepted = ["{weight=100,priority=1}", "{weight=501,priority=0}", "{weight=99,color=8,priority=3}", "{}"];
+        let rejected = ["{weight=501,priority=1}", "{weight=99,color=3}", "{weight=10,color=5,priority=2}"];
+        for part in accepted {
+            assert!(tree.is_accepted(&tree.resolve(&Part::new(part).unwrap())), "{}", part);
+        }
+        for part in rejected {
+            assert!(!tree.is_accepted(&tree.resolve(&Part::new(part).unwrap())), "{}", part);
+        }
+        assert_eq!(Part::new("{weight=501,priority=0,extra=4}").unwrap().rating(), 505);
+
+        /* count by brute force over small ranges */
+        let mut expected = 0;
+        for weight in 0 .. 600 {
+            for color in 0 .. 10 {
+                for priority in 0 .. 4 {
+                    let part = Part::new(&format!("{{weight={},color={},priority={}}}", weight, color, priority)).unwrap();
+                    assert_eq!(tree.is_accepted(&tree.resolve(&part)), original.is_accepted(&part));
+                    expected += original.is_accepted(&part) as u64;
+                }
+            }
+        }
+        let ranges = PartRanges::new(["weight", "color", "priority"], Interval::new(0, 10))
+                         .with("weight", Interval::new(0, 600))
+                         .with("priority", Interval::new(0, 4));
+        assert_eq!(original.accepted_combinations(ranges.clone()), expected);
+        assert_eq!(workflows.accepted_combinations(ranges), expected);
+    }
+}
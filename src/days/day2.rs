@@ -1,42 +1,56 @@
 use std::collections::HashMap;
 use regex::Regex;
+use std::fmt::Display;
+use crate::{Error, Solution};
 
-static DAY: u8 = 2;
+pub struct Day2;
 
-fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", sum_possible(&input));
-    println!("{DAY}b: {}", sum_powers(&input));
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let re = Regex::new("^Game ([0-9]+): (.*)").unwrap();
+        crate::parse_lines(input, |line| Game::new(&re, line))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_possible(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_powers(input))
+    }
 }
 
-struct Game {
+pub struct Game {
     id: usize,
     infos: Vec<HashMap<String, usize>>,
 }
 
 impl Game {
-    fn new(input: &str) -> Game {
-        let re = Regex::new("^Game ([0-9]+): (.*)").unwrap();
-        let caps = re.captures(input).unwrap();
+    fn new(re: &Regex, input: &str) -> Result<Game, String> {
+        let caps = re.captures(input).ok_or("expected \"Game N: ...\"")?;
 
-        let id = caps[1].parse().unwrap();
+        let id = caps[1].parse().map_err(|_| "invalid game id")?;
         let mut infos = Vec::new();
         for info in caps[2].split("; ") {
             let mut color_amount = HashMap::new();
             for color_info in info.split(", ") {
-                let (amount, color) = color_info.split_once(' ').unwrap();
-                let amount = amount.parse().unwrap();
+                let (amount, color) = color_info.split_once(' ')
+                                                .ok_or_else(|| format!("expected amount and color in {:?}", color_info))?;
+                let amount = amount.parse().map_err(|_| format!("invalid amount in {:?}", color_info))?;
                 color_amount.insert(color.to_string(), amount);
             }
             infos.push(color_amount);
         }
-        Game { id, infos }
+        Ok(Game { id, infos })
     }
 
     fn is_possible(&self, bagged: &HashMap<String, usize>) -> bool {
         for info in &self.infos {
             for (color, amount) in info {
-                if bagged[color] < *amount {
+                if bagged.get(color).copied().unwrap_or(0) < *amount {
                     return false
                 }
             }
@@ -59,10 +73,7 @@ impl Game {
     }
 }
 
-fn sum_possible(input: &[String]) -> usize {
-    let games = input.iter()
-                     .map(|x| Game::new(x))
-                     .collect::<Vec<Game>>();
+fn sum_possible(games: &[Game]) -> usize {
     let bag_content = HashMap::from([
         ("red".to_string(), 12),
         ("green".to_string(), 13),
@@ -74,9 +85,8 @@ fn sum_possible(input: &[String]) -> usize {
          .sum()
 }
 
-fn sum_powers(input: &[String]) -> usize {
-    input.iter()
-         .map(|x| Game::new(x))
+fn sum_powers(games: &[Game]) -> usize {
+    games.iter()
          .map(|g| g.power())
          .sum()
 }
@@ -94,7 +104,11 @@ mod tests {
             "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
             "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
          ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
-        assert_eq!(sum_possible(&input), 8);
-        assert_eq!(sum_powers(&input), 2286);
+        let games = Day2::parse(&input.join("\n")).unwrap();
+        assert_eq!(sum_possible(&games), 8);
+        assert_eq!(sum_powers(&games), 2286);
+
+        assert!(Day2::parse("Game 1: 3 blue, 4red").is_err());
+        assert!(Day2::parse("Game x: 3 blue").is_err());
     }
 }
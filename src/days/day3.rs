@@ -1,49 +1,37 @@
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use crate::geometry::Position;
+use crate::{Error, Grid, Solution};
 
-static DAY: u8 = 3;
+pub struct Day3;
 
-fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", part_sum(&input));
-    println!("{DAY}b: {}", gear_ratio_sum(&input));
-}
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Schematics;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Schematics { grid: Grid::from_lines(&crate::lines(input), |c| c)? })
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_sum(input))
+    }
 
-#[derive(Eq, PartialEq, Clone, Copy, Hash)]
-struct Position {
-    x: isize,
-    y: isize,
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(gear_ratio_sum(input))
+    }
 }
 
-struct Schematics {
-    part_positions: HashMap<Position, char>,
+pub struct Schematics {
+    grid: Grid<char>,
 }
 
 impl Schematics {
-    fn new(input: &[String]) -> Schematics {
-        let mut part_positions = HashMap::new();
-        for (y, line) in input.iter().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c != '.' {
-                    let pos = Position { x: x as isize, y: y as isize };
-                    if !c.is_ascii_digit() {
-                        part_positions.insert(pos, c);
-                    }
-                }
-            }
-        }
-        Schematics { part_positions }
-    }
-
     fn get_neighboring_parts(&self, pos: &Position) -> HashMap<Position, char> {
-        let mut parts = HashMap::new();
-        for y in -1 ..= 1 {
-            for x in -1 ..= 1 {
-                if let Some(part) = self.part_positions.get(&Position { x: pos.x + x, y: pos.y + y}) {
-                    parts.insert(Position { x: pos.x + x, y: pos.y + y }, *part);
-                }
-            }
-        }
-        parts
+        self.grid.neighbors8(*pos)
+                 .map(|pos| (pos, self.grid[pos]))
+                 .filter(|&(_, c)| c != '.' && !c.is_ascii_digit())
+                 .collect()
     }
 
     fn has_neighboring_part(&self, pos: &Position) -> bool {
@@ -51,14 +39,13 @@ impl Schematics {
     }
 }
 
-fn part_sum(input: &[String]) -> u32 {
-    let schematics = Schematics::new(input);
+fn part_sum(schematics: &Schematics) -> u32 {
     let mut part_numbers = Vec::new();
 
-    for (y, line) in input.iter().enumerate() {
+    for (y, line) in schematics.grid.rows().enumerate() {
         let mut number = 0;
         let mut is_part_number = false;
-        for (x, c) in line.chars().enumerate() {
+        for (x, c) in line.iter().enumerate() {
             if let Some(digit) = c.to_digit(10) {
                 number *= 10;
                 number += digit;
@@ -84,14 +71,13 @@ fn part_sum(input: &[String]) -> u32 {
                 .sum()
 }
 
-fn gear_ratio_sum(input: &[String]) -> u32 {
-    let schematics = Schematics::new(input);
+fn gear_ratio_sum(schematics: &Schematics) -> u32 {
     let mut gear_neighbors = HashMap::<Position, Vec<u32>>::new();
 
-    for (y, line) in input.iter().enumerate() {
+    for (y, line) in schematics.grid.rows().enumerate() {
         let mut number = 0;
         let mut neighbors = HashSet::new();
-        for (x, c) in line.chars().enumerate() {
+        for (x, c) in line.iter().enumerate() {
             let pos = Position { x: x as isize, y: y as isize };
             if let Some(digit) = c.to_digit(10) {
                 number *= 10;
@@ -142,7 +128,9 @@ mod tests {
             "...$.*....",
             ".664.598..",
         ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
-        assert_eq!(part_sum(&input), 4361);
-        assert_eq!(gear_ratio_sum(&input), 467835);
+        let schematics = Day3::parse(&input.join("\n")).unwrap();
+        assert_eq!(part_sum(&schematics), 4361);
+        assert_eq!(gear_ratio_sum(&schematics), 467835);
+        assert!(Day3::parse("467..\n...*").is_err());
     }
 }
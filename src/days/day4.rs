@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use crate::{Error, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        crate::parse_lines(input, Card::new)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(total_points(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(total_cards(input))
+    }
+}
+
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    numbers_you_have: HashSet<u32>,
+}
+
+impl Card {
+    fn new(input: &str) -> Result<Card, String> {
+        let (_id, numbers) = input.split_once(": ").ok_or("expected \"Card N: \"")?;
+        let (win, have) = numbers.split_once(" | ").ok_or("expected \" | \" between the numbers")?;
+        let parse_numbers = |numbers: &str| numbers.split(' ')
+                                                   .filter(|x| !x.is_empty())
+                                                   .map(|x| x.parse().map_err(|_| format!("invalid number {:?}", x)))
+                                                   .collect::<Result<HashSet<_>, _>>();
+        let winning_numbers = parse_numbers(win)?;
+        let numbers_you_have = parse_numbers(have)?;
+        Ok(Card { winning_numbers, numbers_you_have })
+    }
+
+    fn matching_numbers(&self) -> HashSet<u32> {
+        self.winning_numbers.intersection(&self.numbers_you_have).copied().collect()
+    }
+
+    fn points(&self) -> u32 {
+        (1 << self.matching_numbers().len()) / 2
+    }
+}
+
+fn total_points(cards: &[Card]) -> u32 {
+    cards.iter()
+         .map(|c| c.points())
+         .sum()
+}
+
+fn total_cards(cards: &[Card]) -> u32 {
+    let mut amounts = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let matches = card.matching_numbers().len();
+        let copies = amounts[i];
+        for amount in amounts.iter_mut().take(i + 1 + matches).skip(i + 1) {
+            *amount += copies;
+        }
+    }
+
+    amounts.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+            "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+            "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+            "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+            "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let cards = Day4::parse(&input.join("\n")).unwrap();
+        assert_eq!(total_points(&cards), 13);
+        assert_eq!(total_cards(&cards), 30);
+        assert!(Day4::parse("Card 1: 41 48 | 83 x").is_err());
+        assert!(Day4::parse("Card 1: 41 48 83").is_err());
+    }
+}
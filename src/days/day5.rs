@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use crate::interval::{Interval, RangeSet};
+use crate::{Error, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let almanac = Almanac::new(&crate::lines(input)).map_err(|err| Error::Invalid(err.to_string()))?;
+        almanac.chain("seed", "location").map_err(|err| Error::Invalid(err.to_string()))?;
+        Ok(almanac)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(lowest_location(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(lowest_location2(input))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct RangeMap {
+    dst_start: u64,
+    src_start: u64,
+    range: u64,
+}
+
+impl RangeMap {
+    fn new(input: &str) -> Option<RangeMap> {
+        let values = input.split(' ')
+                          .map(|x| x.parse().ok())
+                          .collect::<Option<Vec<u64>>>()?;
+        /* source and destination have to lie within the domain */
+        let end = DOMAIN.end as u64;
+        if values.len() != 3 || values.iter().any(|&value| value > end) || values[0].max(values[1]) + values[2] > end {
+            return None;
+        }
+
+        Some(RangeMap { dst_start: values[0], src_start: values[1], range: values[2] })
+    }
+
+    fn source(&self) -> Interval {
+        Interval::with_len(self.src_start as i64, self.range as i64)
+    }
+
+    fn offset(&self) -> i64 {
+        self.dst_start as i64 - self.src_start as i64
+    }
+}
+
+#[derive(Clone)]
+struct RangeCategory {
+    source: String,
+    destination: String,
+    ranges: Vec<RangeMap>,
+}
+
+impl RangeCategory {
+    fn map(&self, val: u64) -> u64 {
+        for range in &self.ranges {
+            if range.src_start <= val && val < range.src_start + range.range {
+                return range.dst_start + val - range.src_start
+            }
+        }
+        val
+    }
+
+    fn map_ranges(&self, input: &RangeSet) -> RangeSet {
+        let mut unmapped = input.clone();
+        let mut mapped = Vec::new();
+        for range in &self.ranges {
+            let source = RangeSet::from([range.source()]);
+            mapped.extend(unmapped.intersection(&source).shift(range.offset()).iter());
+            unmapped = unmapped.difference(&source);
+        }
+        /* values not covered by any range keep their number */
+        RangeSet::from(mapped).union(&unmapped)
+    }
+}
+
+/* values that can occur in an almanac; everything in between maps to itself */
+const DOMAIN: Interval = Interval { start: 0, end: 1 << 48 };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Segment {
+    pub source: Interval,
+    pub offset: i64,
+}
+
+impl Segment {
+    pub fn image(&self) -> Interval {
+        self.source.shift(self.offset)
+    }
+}
+
+/* piecewise-linear map covering the whole domain, sorted by source */
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PiecewiseMap {
+    segments: Vec<Segment>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> PiecewiseMap {
+        PiecewiseMap { segments: vec![Segment { source: DOMAIN, offset: 0 }] }
+    }
+
+    fn from_category(category: &RangeCategory) -> PiecewiseMap {
+        let mut segments = category.ranges.iter()
+                                          .map(|range| Segment { source: range.source(), offset: range.offset() })
+                                          .collect::<Vec<_>>();
+        let sources = RangeSet::from(segments.iter().map(|segment| segment.source));
+        let unmapped = RangeSet::from([DOMAIN]).difference(&sources);
+        segments.extend(unmapped.iter().map(|&source| Segment { source, offset: 0 }));
+        segments.sort_unstable_by_key(|segment| segment.source);
+        PiecewiseMap { segments }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        let segment = self.segments.iter()
+                                   .find(|segment| segment.source.contains(value))
+                                   .expect("value outside of almanac domain");
+        value + segment.offset
+    }
+
+    /* first apply self, then the next map */
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut segments = Vec::new();
+        for segment in &self.segments {
+            let image = segment.image();
+            for next_segment in next.segments.iter().filter(|s| s.source.overlaps(&image)) {
+                let overlap = image.intersection(&next_segment.source).unwrap();
+                segments.push(Segment {
+                    source: overlap.shift(-segment.offset),
+                    offset: segment.offset + next_segment.offset,
+                });
+            }
+        }
+        segments.sort_unstable_by_key(|segment| segment.source);
+        PiecewiseMap { segments }
+    }
+
+    /* all values the given inputs are mapped to */
+    pub fn image(&self, input: &RangeSet) -> RangeSet {
+        let mut output = Vec::new();
+        for segment in &self.segments {
+            let part = input.intersection(&RangeSet::from([segment.source]));
+            output.extend(part.shift(segment.offset).iter());
+        }
+        RangeSet::from(output)
+    }
+
+    /* all values that are mapped into the given outputs */
+    pub fn preimage(&self, output: &RangeSet) -> RangeSet {
+        let mut input = Vec::new();
+        for segment in &self.segments {
+            let part = output.intersection(&RangeSet::from([segment.image()]));
+            input.extend(part.shift(-segment.offset).iter());
+        }
+        RangeSet::from(input)
+    }
+}
+
+fn read_seed_ranges(almanac: &Almanac) -> RangeSet {
+    RangeSet::from(almanac.seeds.chunks(2).map(|x| Interval::with_len(x[0], x[1])))
+}
+
+/* all almanac categories composed into a single seed-to-location map */
+pub fn almanac_map(almanac: &Almanac) -> PiecewiseMap {
+    almanac.map_between("seed", "location").unwrap()
+}
+
+/* seeds from the seed ranges that end up in the given locations */
+pub fn seeds_for_locations(almanac: &Almanac, locations: Interval) -> RangeSet {
+    almanac_map(almanac).preimage(&RangeSet::from([locations]))
+                        .intersection(&read_seed_ranges(almanac))
+}
+
+/* walks the location segments upwards until no lower location can follow */
+pub fn lowest_reachable_location(almanac: &Almanac) -> u64 {
+    let map = almanac_map(almanac);
+    let seeds = read_seed_ranges(almanac);
+    let mut segments = map.segments().to_vec();
+    segments.sort_unstable_by_key(|segment| segment.image());
+
+    let mut lowest = None;
+    for segment in segments {
+        if lowest.is_some_and(|lowest| segment.image().start >= lowest) {
+            break;
+        }
+        let reachable = seeds.intersection(&RangeSet::from([segment.source]));
+        if let Some(seed) = reachable.min() {
+            let location = seed + segment.offset;
+            lowest = Some(lowest.map_or(location, |lowest: i64| lowest.min(location)));
+        }
+    }
+    lowest.expect("no location reachable") as u64
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AlmanacError {
+    MissingSeeds,
+    InvalidSeeds(String),
+    InvalidHeader(String),
+    InvalidRange(String),
+    DuplicateCategory(String),
+    OverlappingRanges { category: String, first: Interval, second: Interval },
+    MissingLink { from: String, to: String },
+    CyclicChain(String),
+}
+
+impl Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlmanacError::MissingSeeds => write!(f, "almanac doesn't start with a seeds line"),
+            AlmanacError::InvalidSeeds(seeds) => write!(f, "invalid seeds {:?}, expected pairs of start and length", seeds),
+            AlmanacError::InvalidHeader(line) => write!(f, "invalid map header {:?}", line),
+            AlmanacError::InvalidRange(line) => write!(f, "invalid range {:?}", line),
+            AlmanacError::DuplicateCategory(name) => write!(f, "multiple maps from category {:?}", name),
+            AlmanacError::OverlappingRanges { category, first, second } =>
+                write!(f, "{} map has overlapping source ranges {}..{} and {}..{}",
+                       category, first.start, first.end, second.start, second.end),
+            AlmanacError::MissingLink { from, to } => write!(f, "no map from {:?} on the way to {:?}", from, to),
+            AlmanacError::CyclicChain(name) => write!(f, "category chain loops back to {:?}", name),
+        }
+    }
+}
+
+/* the seeds, and the almanac categories keyed by their source category */
+pub struct Almanac {
+    seeds: Vec<i64>,
+    categories: HashMap<String, RangeCategory>,
+}
+
+impl Almanac {
+    pub fn new(input: &[String]) -> Result<Almanac, AlmanacError> {
+        let seeds = input.first()
+                         .and_then(|line| line.strip_prefix("seeds: "))
+                         .ok_or(AlmanacError::MissingSeeds)?;
+        let invalid_seeds = || AlmanacError::InvalidSeeds(seeds.to_string());
+        let seeds = seeds.split(' ')
+                         .map(|x| x.parse().ok().filter(|&seed| DOMAIN.contains(seed)))
+                         .collect::<Option<Vec<_>>>()
+                         .ok_or_else(invalid_seeds)?;
+        /* the seed ranges are pairs of start and length, none of them empty */
+        if seeds.len() % 2 != 0 || seeds.chunks(2).any(|pair| pair[1] == 0 || pair[0] + pair[1] > DOMAIN.end) {
+            return Err(invalid_seeds());
+        }
+
+        let mut categories = Vec::new();
+        for line in input.iter().skip(1).filter(|line| !line.is_empty()) {
+            if !line.chars().next().unwrap().is_ascii_digit() {
+                /* new category, "seed-to-soil map:" */
+                let (source, destination) = line.strip_suffix(" map:")
+                                                .and_then(|name| name.split_once("-to-"))
+                                                .ok_or_else(|| AlmanacError::InvalidHeader(line.clone()))?;
+                categories.push(RangeCategory {
+                    source: source.to_string(),
+                    destination: destination.to_string(),
+                    ranges: Vec::new(),
+                });
+                continue;
+            }
+            let range = RangeMap::new(line).ok_or_else(|| AlmanacError::InvalidRange(line.clone()))?;
+            match categories.last_mut() {
+                Some(category) => category.ranges.push(range),
+                None => return Err(AlmanacError::InvalidHeader(line.clone())),
+            }
+        }
+
+        let mut by_source = HashMap::new();
+        for category in categories {
+            let mut sources = category.ranges.iter().map(RangeMap::source).collect::<Vec<_>>();
+            sources.sort_unstable();
+            if let Some(pair) = sources.windows(2).find(|pair| pair[0].overlaps(&pair[1])) {
+                return Err(AlmanacError::OverlappingRanges {
+                    category: format!("{}-to-{}", category.source, category.destination),
+                    first: pair[0],
+                    second: pair[1],
+                });
+            }
+            if by_source.contains_key(&category.source) {
+                return Err(AlmanacError::DuplicateCategory(category.source));
+            }
+            by_source.insert(category.source.clone(), category);
+        }
+        Ok(Almanac { seeds, categories: by_source })
+    }
+
+    fn chain(&self, from: &str, to: &str) -> Result<Vec<&RangeCategory>, AlmanacError> {
+        let mut chain: Vec<&RangeCategory> = Vec::new();
+        let mut current = from;
+        while current != to {
+            if chain.iter().any(|category| category.source == current) {
+                return Err(AlmanacError::CyclicChain(current.to_string()));
+            }
+            let category = self.categories.get(current).ok_or_else(|| AlmanacError::MissingLink {
+                from: current.to_string(),
+                to: to.to_string(),
+            })?;
+            chain.push(category);
+            current = &category.destination;
+        }
+        Ok(chain)
+    }
+
+    /* composed map between two categories, e.g. from soil to humidity */
+    pub fn map_between(&self, from: &str, to: &str) -> Result<PiecewiseMap, AlmanacError> {
+        Ok(self.chain(from, to)?.into_iter()
+                                .map(PiecewiseMap::from_category)
+                                .fold(PiecewiseMap::identity(), |map, next| map.then(&next)))
+    }
+}
+
+/* categories from seed to location, resolved by name */
+fn read_range_map(almanac: &Almanac) -> Vec<&RangeCategory> {
+    almanac.chain("seed", "location").unwrap()
+}
+
+fn lowest_location(almanac: &Almanac) -> u64 {
+    let range_maps = read_range_map(almanac);
+
+    let mut locations = Vec::new();
+    for &seed in &almanac.seeds {
+        let mut next_val = seed as u64;
+        for category in &range_maps {
+            next_val = category.map(next_val);
+        }
+        locations.push(next_val);
+    }
+
+    *locations.iter().min().unwrap()
+}
+
+fn lowest_location2(almanac: &Almanac) -> u64 {
+    let seed_ranges = read_seed_ranges(almanac);
+
+    let range_maps = read_range_map(almanac);
+    let locations = range_maps.iter()
+                              .fold(seed_ranges, |ranges, category| category.map_ranges(&ranges));
+
+    locations.min().unwrap() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lowest_location_brute_force(almanac: &Almanac) -> u64 {
+        let range_maps = read_range_map(almanac);
+
+        let mut lowest_location = u64::MAX;
+        for range in almanac.seeds.chunks(2) {
+            for seed in range[0] as u64 .. (range[0] + range[1]) as u64 {
+                let location = range_maps.iter().fold(seed, |val, category| category.map(val));
+                lowest_location = lowest_location.min(location);
+            }
+        }
+        lowest_location
+    }
+
+    #[test]
+    fn test() {
+        let input = [
+            "seeds: 79 14 55 13",
+            "",
+            "seed-to-soil map:",
+            "50 98 2",
+            "52 50 48",
+            "",
+            "soil-to-fertilizer map:",
+            "0 15 37",
+            "37 52 2",
+            "39 0 15",
+            "",
+            "fertilizer-to-water map:",
+            "49 53 8",
+            "0 11 42",
+            "42 0 7",
+            "57 7 4",
+            "",
+            "water-to-light map:",
+            "88 18 7",
+            "18 25 70",
+            "",
+            "light-to-temperature map:",
+            "45 77 23",
+            "81 45 19",
+            "68 64 13",
+            "",
+            "temperature-to-humidity map:",
+            "0 69 1",
+            "1 0 69",
+            "",
+            "humidity-to-location map:",
+            "60 56 37",
+            "56 93 4"
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day5::parse(&input.join("\n")).unwrap();
+        assert_eq!(lowest_location(&input), 35);
+        assert_eq!(lowest_location2(&input), 46);
+        assert_eq!(lowest_location2(&input), lowest_location_brute_force(&input));
+
+        let map = almanac_map(&input);
+        let range_maps = read_range_map(&input);
+        for seed in 0 .. 110 {
+            let location = range_maps.iter().fold(seed as u64, |val, category| category.map(val));
+            assert_eq!(map.apply(seed), location as i64);
+            assert!(map.preimage(&RangeSet::from([Interval::with_len(location as i64, 1)])).contains(seed));
+        }
+        assert_eq!(map.image(&read_seed_ranges(&input)).min(), Some(46));
+        assert_eq!(lowest_reachable_location(&input), 46);
+
+        /* seed 82 is the only one reaching location 46 */
+        assert_eq!(seeds_for_locations(&input, Interval::new(0, 47)), RangeSet::from([Interval::new(82, 83)]));
+        assert!(seeds_for_locations(&input, Interval::new(0, 46)).is_empty());
+    }
+
+    #[test]
+    fn test_almanac() {
+        let almanac = |lines: &[&str]| Almanac::new(&lines.iter().map(|&x| String::from(x)).collect::<Vec<_>>());
+
+        /* blocks out of order are resolved by name */
+        let input = [
+            "seeds: 1 2",
+            "",
+            "soil-to-water map:",
+            "100 0 10",
+            "",
+            "seed-to-soil map:",
+            "5 0 10",
+        ];
+        let map = almanac(&input).unwrap().map_between("seed", "water").unwrap();
+        assert_eq!(map.apply(3), 108);
+        assert_eq!(map.apply(7), 12);
+        let map = almanac(&input).unwrap().map_between("soil", "water").unwrap();
+        assert_eq!(map.apply(3), 103);
+        assert_eq!(almanac(&input).unwrap().map_between("seed", "seed").unwrap(), PiecewiseMap::identity());
+        assert_eq!(almanac(&input).unwrap().map_between("seed", "location").unwrap_err(),
+                   AlmanacError::MissingLink { from: "water".to_string(), to: "location".to_string() });
+
+        assert_eq!(almanac(&["seeds: 1 2", "", "seed-to-soil map:", "5 0 10", "", "seed-to-water map:"]).err(),
+                   Some(AlmanacError::DuplicateCategory("seed".to_string())));
+        assert_eq!(almanac(&["seeds: 1 2", "", "seed-to-soil map:", "5 0 10", "50 8 3"]).err(),
+                   Some(AlmanacError::OverlappingRanges {
+                       category: "seed-to-soil".to_string(),
+                       first: Interval::new(0, 10),
+                       second: Interval::new(8, 11),
+                   }));
+        assert_eq!(almanac(&["seeds: 1 2", "", "seed to soil:"]).err(),
+                   Some(AlmanacError::InvalidHeader("seed to soil:".to_string())));
+        assert_eq!(almanac(&["seeds: 1 2", "", "seed-to-soil map:", "5 x 10"]).err(),
+                   Some(AlmanacError::InvalidRange("5 x 10".to_string())));
+        assert_eq!(almanac(&["seed-to-soil map:"]).err(), Some(AlmanacError::MissingSeeds));
+        for seeds in ["1 2 3", "1 x", "-1 2", "1 281474976710656", "1 0", "3 4 1 0"] {
+            assert_eq!(almanac(&[&format!("seeds: {}", seeds)]).err(), Some(AlmanacError::InvalidSeeds(seeds.to_string())));
+        }
+        assert_eq!(almanac(&["seeds: 1 2", "", "seed-to-soil map:", "0 281474976710655 2"]).err(),
+                   Some(AlmanacError::InvalidRange("0 281474976710655 2".to_string())));
+        assert!(Day5::parse("seeds: 1 2 3\n\nseed-to-location map:\n5 0 10").is_err());
+
+        let cyclic = almanac(&["seeds: 1 2", "", "seed-to-soil map:", "", "soil-to-seed map:"]).unwrap();
+        assert_eq!(cyclic.map_between("seed", "location").unwrap_err(), AlmanacError::CyclicChain("seed".to_string()));
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+use crate::{Error, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Races::new(&crate::lines(input)).map_err(Error::Invalid)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(possible_ways(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(possible_ways_single_race(input))
+    }
+}
+
+/* times and record distances, read as separate races and as one race with
+   the spaces between the digits removed */
+pub struct Races {
+    races: Vec<(u64, u64)>,
+    single_race: (u64, u64),
+}
+
+impl Races {
+    fn new(input: &[String]) -> Result<Races, String> {
+        let line = |i: usize, label: &str| {
+            input.get(i)
+                 .and_then(|line| line.strip_prefix(label))
+                 .ok_or_else(|| format!("expected line {} to start with {:?}", i + 1, label))
+        };
+        let get_numbers = |x: &str| {
+            x.split(' ')
+             .filter(|s| !s.is_empty())
+             .map(|s| s.parse().map_err(|_| format!("invalid number {:?}", s)))
+             .collect::<Result<Vec<u64>, _>>()
+        };
+        let get_number = |x: &str| {
+            let digits = x.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+            digits.parse::<u64>().map_err(|_| format!("invalid number {:?}", digits))
+        };
+        let (times_line, distances_line) = (line(0, "Time:")?, line(1, "Distance:")?);
+        let (times, distances) = (get_numbers(times_line)?, get_numbers(distances_line)?);
+        if times.len() != distances.len() {
+            return Err("times and distances differ in count".to_string());
+        }
+        let races = times.into_iter().zip(distances).collect();
+        Ok(Races { races, single_race: (get_number(times_line)?, get_number(distances_line)?) })
+    }
+}
+
+fn count_ways(time: u64, record: u64) -> u64 {
+    let mut ways = 0;
+    for hold_button in 1 .. time {
+        let moving_time = time - hold_button;
+        let boat_distance = moving_time * hold_button;
+        if boat_distance > record {
+            ways += 1;
+        }
+    }
+    ways
+}
+
+fn possible_ways(races: &Races) -> u64 {
+    races.races.iter()
+               .map(|&(time, distance)| count_ways(time, distance))
+               .product()
+}
+
+fn possible_ways_single_race(races: &Races) -> u64 {
+    let (time, distance) = races.single_race;
+    count_ways(time, distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "Time:      7  15   30",
+            "Distance:  9  40  200",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let races = Day6::parse(&input.join("\n")).unwrap();
+        assert_eq!(possible_ways(&races), 288);
+        assert_eq!(possible_ways_single_race(&races), 71503);
+
+        assert!(Day6::parse("Time: 7 15\nDistance: 9").is_err());
+        assert!(Day6::parse("Time: 7 x\nDistance: 9 40").is_err());
+        assert!(Day6::parse("0 3 6 9 12 15\n1 3 6 10 15 21").is_err());
+    }
+}
@@ -1,14 +1,27 @@
 use std::{cmp::Ordering, collections::HashMap};
 use std::sync::Mutex;
-
-static DAY: u8 = 7;
+use std::fmt::Display;
+use crate::{Error, Solution};
 
 static J_IS_JOKER: Mutex<bool> = Mutex::new(false);
 
-fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", winnings_no_joker(&input));
-    println!("{DAY}b: {}", winnings_joker(&input));
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        crate::parse_lines(input, Hand::new)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(winnings_no_joker(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(winnings_joker(input))
+    }
 }
 
 fn j_is_joker() -> bool {
@@ -34,22 +47,22 @@ enum Card {
 }
 
 impl Card {
-    fn new(c: char) -> Card {
+    fn new(c: char) -> Option<Card> {
         match c {
-            'A' => Card::Ace,
-            'K' => Card::King,
-            'Q' => Card::Queen,
-            'J' => if j_is_joker() { Card::Joker } else { Card::Jack },
-            'T' => Card::Ten,
-            '9' => Card::Nine,
-            '8' => Card::Eight,
-            '7' => Card::Seven,
-            '6' => Card::Six,
-            '5' => Card::Five,
-            '4' => Card::Four,
-            '3' => Card::Three,
-            '2' => Card::Two,
-            _ => panic!("invalid card character"),
+            'A' => Some(Card::Ace),
+            'K' => Some(Card::King),
+            'Q' => Some(Card::Queen),
+            'J' => Some(Card::Jack),
+            'T' => Some(Card::Ten),
+            '9' => Some(Card::Nine),
+            '8' => Some(Card::Eight),
+            '7' => Some(Card::Seven),
+            '6' => Some(Card::Six),
+            '5' => Some(Card::Five),
+            '4' => Some(Card::Four),
+            '3' => Some(Card::Three),
+            '2' => Some(Card::Two),
+            _ => None,
         }
     }
 }
@@ -118,22 +131,31 @@ impl HandType {
     }
 }
 
-struct Hand {
+#[derive(Clone)]
+pub struct Hand {
     cards: [Card; 5],
     bid: u32,
 }
 
 impl Hand {
-    fn new(input: &str) -> Hand {
-        let (values, bid) = input.split_once(' ').unwrap();
-        assert_eq!(values.len(), 5);
+    fn new(input: &str) -> Result<Hand, String> {
+        let (values, bid) = input.split_once(' ').ok_or("expected cards and bid")?;
+        if values.chars().count() != 5 {
+            return Err(format!("expected 5 cards, got {:?}", values));
+        }
         let mut cards = [Card::Two; 5];
         for (i, c) in values.chars().enumerate() {
-            cards[i] = Card::new(c);
+            cards[i] = Card::new(c).ok_or_else(|| format!("invalid card {:?}", c))?;
         }
-        let bid = bid.parse().unwrap();
+        let bid = bid.parse().map_err(|_| format!("invalid bid {:?}", bid))?;
 
-        Hand { cards, bid }
+        Ok(Hand { cards, bid })
+    }
+
+    /* the same hand with jacks read as jokers */
+    fn with_jokers(&self) -> Hand {
+        let cards = self.cards.map(|card| if card == Card::Jack { Card::Joker } else { card });
+        Hand { cards, bid: self.bid }
     }
 
     fn hand_type(&self) -> HandType {
@@ -176,10 +198,7 @@ impl Ord for Hand {
     }
 }
 
-fn winnings(input: &[String]) -> u32 {
-    let mut hands = input.iter()
-                     .map(|x| Hand::new(x))
-                     .collect::<Vec<_>>();
+fn winnings(mut hands: Vec<Hand>) -> u32 {
     hands.sort_unstable();
 
     hands.iter()
@@ -188,14 +207,14 @@ fn winnings(input: &[String]) -> u32 {
          .sum()
 }
 
-fn winnings_no_joker(input: &[String]) -> u32 {
+fn winnings_no_joker(hands: &[Hand]) -> u32 {
     *J_IS_JOKER.lock().unwrap() = false;
-    winnings(input)
+    winnings(hands.to_vec())
 }
 
-fn winnings_joker(input: &[String]) -> u32 {
+fn winnings_joker(hands: &[Hand]) -> u32 {
     *J_IS_JOKER.lock().unwrap() = true;
-    winnings(input)
+    winnings(hands.iter().map(Hand::with_jokers).collect())
 }
 
 #[cfg(test)]
@@ -211,7 +230,12 @@ mod tests {
             "KTJJT 220",
             "QQQJA 483",
         ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
-        assert_eq!(winnings_no_joker(&input), 6440);
-        assert_eq!(winnings_joker(&input), 5905);
+        let hands = Day7::parse(&input.join("\n")).unwrap();
+        assert_eq!(winnings_no_joker(&hands), 6440);
+        assert_eq!(winnings_joker(&hands), 5905);
+
+        assert!(Day7::parse("32T3X 765").is_err());
+        assert!(Day7::parse("32T3 765").is_err());
+        assert!(Day7::parse("32T3K").is_err());
     }
 }
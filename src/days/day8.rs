@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use crate::geometry::Turn;
+use crate::math;
+use crate::{Error, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Map::new(&crate::lines(input))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        required_steps(input)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        required_steps_ghost(input)
+    }
+}
+
+fn parse_turn(c: char) -> Option<Turn> {
+    match c {
+        'L' => Some(Turn::Left),
+        'R' => Some(Turn::Right),
+        _ => None,
+    }
+}
+
+struct Choice {
+    left: String,
+    right: String,
+}
+
+impl Choice {
+    fn from(input: &str) -> Option<Choice> {
+        /* "(AAA, ZZZ)" */
+        let tmp = input.strip_prefix('(')?.strip_suffix(')')?;
+        let (left, right) = tmp.split_once(", ")?;
+        Some(Choice { left: left.to_string(), right: right.to_string() })
+    }
+}
+
+pub struct Map {
+    instructions: Vec<Turn>,
+    map: HashMap<String, Choice>,
+}
+
+impl Map {
+    fn new(input: &[String]) -> Result<Map, Error> {
+        let error = |i: usize, message: &str| Error::Parse {
+            line: i + 1,
+            text: input.get(i).cloned().unwrap_or_default(),
+            message: message.to_string(),
+        };
+        let instructions = input.first()
+                                .filter(|line| !line.is_empty())
+                                .and_then(|line| line.chars().map(parse_turn).collect::<Option<Vec<_>>>())
+                                .ok_or_else(|| error(0, "expected L and R instructions"))?;
+
+        let mut map = HashMap::new();
+        for (i, line) in input.iter().enumerate().skip(2) {
+            let (from, choice) = line.split_once(" = ")
+                                     .and_then(|(from, to)| Some((from, Choice::from(to)?)))
+                                     .ok_or_else(|| error(i, "expected \"AAA = (BBB, CCC)\""))?;
+            map.insert(from.to_string(), choice);
+        }
+        for (from, choice) in &map {
+            for to in [&choice.left, &choice.right] {
+                if !map.contains_key(to) {
+                    return Err(Error::Invalid(format!("node {} leads to undefined node {}", from, to)));
+                }
+            }
+        }
+        Ok(Map { instructions, map })
+    }
+
+    fn get_direction(&self, step: usize) -> Turn {
+        let i = step % self.instructions.len();
+        self.instructions[i]
+    }
+
+    fn next_pos(&self, pos: &str, steps: usize) -> String {
+        let choice = self.map.get(pos).unwrap();
+        match self.get_direction(steps) {
+            Turn::Left => choice.left.clone(),
+            Turn::Right => choice.right.clone(),
+        }
+    }
+
+    fn number_steps(&self, from: &str, to: &str) -> Result<usize, Error> {
+        for node in [from, to] {
+            if !self.map.contains_key(node) {
+                return Err(Error::Invalid(format!("no node {}", node)));
+            }
+        }
+        /* after visiting every node at every instruction the walk repeats */
+        let limit = self.map.len() * self.instructions.len();
+        let mut steps = 0;
+        let mut pos = from.to_string();
+        while pos != to {
+            if steps >= limit {
+                return Err(Error::Invalid(format!("{} is never reached from {}", to, from)));
+            }
+            pos = self.next_pos(&pos, steps);
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /* steps from `from` to the next position ending in Z, when the first step
+       is the given step of the instructions; None if no Z is ever reached */
+    fn number_steps_to_z(&self, from: &str, start_step: usize) -> Option<(usize, String)> {
+        /* after visiting every node at every instruction the walk repeats */
+        let limit = self.map.len() * self.instructions.len();
+        let mut steps = 0;
+        let mut pos = from.to_string();
+        loop {
+            pos = self.next_pos(&pos, start_step + steps);
+            steps += 1;
+            if pos.ends_with('Z') {
+                return Some((steps, pos));
+            }
+            if steps >= limit {
+                return None;
+            }
+        }
+    }
+
+    fn number_steps_ghost(&self) -> Result<usize, Error> {
+        let mut positions = self.map.keys()
+                                    .filter(|pos| pos.ends_with('A'))
+                                    .cloned()
+                                    .collect::<Vec<_>>();
+        positions.sort_unstable();
+
+        let mut congruences = Vec::new();
+        let mut min_steps = 0;
+        for pos in &positions {
+            let never_reaches_z = || Error::Invalid(format!("ghost starting at {} never reaches a Z node", pos));
+            let (offset, z_pos) = self.number_steps_to_z(pos, 0).ok_or_else(never_reaches_z)?;
+            let (cycle_len, _) = self.number_steps_to_z(&z_pos, offset).ok_or_else(never_reaches_z)?;
+
+            /* each ghost has to keep cycling back to the same yyZ, at whatever
+               instruction it arrives there, so it is on a Z position after
+               offset + k * cycle_len steps. */
+            let n = self.instructions.len();
+            let phases = n / math::gcd(cycle_len as u64, n as u64) as usize;
+            for k in 0 .. phases {
+                if self.number_steps_to_z(&z_pos, offset + k * cycle_len) != Some((cycle_len, z_pos.clone())) {
+                    return Err(Error::Invalid(format!("ghost starting at {} doesn't keep cycling through {}", pos, z_pos)));
+                }
+            }
+            congruences.push(((offset % cycle_len) as i64, cycle_len as i64));
+            min_steps = min_steps.max(offset);
+        }
+        let (steps, period) = math::crt(&congruences)
+                                  .ok_or_else(|| Error::Invalid("ghosts never meet on Z positions".to_string()))?;
+
+        /* all ghosts first have to reach their cycle */
+        let (steps, period) = (steps as usize, period as usize);
+        if steps < min_steps {
+            Ok(steps + (min_steps - steps).div_ceil(period) * period)
+        } else {
+            Ok(steps)
+        }
+    }
+}
+
+fn required_steps(map: &Map) -> Result<usize, Error> {
+    map.number_steps("AAA", "ZZZ")
+}
+
+fn required_steps_ghost(map: &Map) -> Result<usize, Error> {
+    map.number_steps_ghost()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "RL",
+            "",
+            "AAA = (BBB, CCC)",
+            "BBB = (DDD, EEE)",
+            "CCC = (ZZZ, GGG)",
+            "DDD = (DDD, DDD)",
+            "EEE = (EEE, EEE)",
+            "GGG = (GGG, GGG)",
+            "ZZZ = (ZZZ, ZZZ)",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        assert_eq!(required_steps(&Day8::parse(&input.join("\n")).unwrap()).unwrap(), 2);
+
+        let input = [
+            "LLR",
+            "",
+            "AAA = (BBB, BBB)",
+            "BBB = (AAA, ZZZ)",
+            "ZZZ = (ZZZ, ZZZ)",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        assert_eq!(required_steps(&Day8::parse(&input.join("\n")).unwrap()).unwrap(), 6);
+        assert!(required_steps(&Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap()).is_err());
+
+        let input = [
+            "LR",
+            "",
+            "11A = (11B, XXX)",
+            "11B = (XXX, 11Z)",
+            "11Z = (11B, XXX)",
+            "22A = (22B, XXX)",
+            "22B = (22C, 22C)",
+            "22C = (22Z, 22Z)",
+            "22Z = (22B, 22B)",
+            "XXX = (XXX, XXX)",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let map = Day8::parse(&input.join("\n")).unwrap();
+        assert_eq!(required_steps_ghost(&map).unwrap(), 6);
+        assert!(required_steps(&map).is_err());
+
+        /* 11Z is reached after an odd number of steps, and only leads back
+           to itself when the cycle starts on an R */
+        let input = [
+            "LR",
+            "",
+            "11A = (11Z, XXX)",
+            "11Z = (XXX, 11B)",
+            "11B = (11Z, 11Z)",
+            "XXX = (XXX, XXX)",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        assert_eq!(required_steps_ghost(&Day8::parse(&input.join("\n")).unwrap()).unwrap(), 1);
+
+        /* 11Z leads back to itself on an R, but away on the following L */
+        let input = [
+            "LR",
+            "",
+            "11A = (11Z, XXX)",
+            "11Z = (XXX, 11Z)",
+            "XXX = (XXX, XXX)",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        assert!(required_steps_ghost(&Day8::parse(&input.join("\n")).unwrap()).is_err());
+        assert!(required_steps_ghost(&Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").unwrap()).is_err());
+
+        assert!(Day8::parse("LX\n\nAAA = (AAA, AAA)").is_err());
+        assert!(Day8::parse("LR\n\nAAA = (AAA AAA)").is_err());
+        assert!(Day8::parse("LR\n\nAAA = (BBB, AAA)").is_err());
+    }
+}
@@ -1,9 +1,27 @@
-static DAY: u8 = 9;
+use std::fmt::Display;
+use crate::{Error, Solution};
 
-fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", sum_extrapolations(&input, false));
-    println!("{DAY}b: {}", sum_extrapolations(&input, true));
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        crate::parse_lines(input, |line| {
+            line.split(' ')
+                .map(|n| n.parse().map_err(|_| format!("invalid number {:?}", n)))
+                .collect()
+        })
+    }
+
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_extrapolations(input, false))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_extrapolations(input, true))
+    }
 }
 
 fn extrapolate(input: &[i32], previous: bool) -> i32 {
@@ -24,10 +42,9 @@ fn extrapolate(input: &[i32], previous: bool) -> i32 {
     }
 }
 
-fn sum_extrapolations(input: &[String], previous: bool) -> i32 {
+fn sum_extrapolations(input: &[Vec<i32>], previous: bool) -> i32 {
     input.iter()
-         .map(|x| x.split(' ').map(|n| n.parse().unwrap()).collect::<Vec<_>>())
-         .map(|numbers| extrapolate(&numbers, previous))
+         .map(|numbers| extrapolate(numbers, previous))
          .sum()
 }
 
@@ -42,7 +59,9 @@ mod tests {
             "1 3 6 10 15 21",
             "10 13 16 21 30 45",
         ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        let input = Day9::parse(&input.join("\n")).unwrap();
         assert_eq!(sum_extrapolations(&input, false), 114);
         assert_eq!(sum_extrapolations(&input, true), 2);
+        assert!(Day9::parse("0 3 x").is_err());
     }
 }
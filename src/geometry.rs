@@ -0,0 +1,234 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use crate::math::gcd;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, PartialOrd, Ord)]
+pub struct Position {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Position {
+    pub const fn new(x: isize, y: isize) -> Position {
+        Position { x, y }
+    }
+
+    pub fn manhattan(&self, other: Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn step(&self, direction: Direction) -> Position {
+        *self + direction.delta()
+    }
+
+    pub fn step_by(&self, direction: Direction, steps: isize) -> Position {
+        *self + direction.delta() * steps
+    }
+
+    /* None if the position would overflow */
+    pub fn checked_step_by(&self, direction: Direction, steps: isize) -> Option<Position> {
+        let delta = direction.delta();
+        Some(Position {
+            x: self.x.checked_add(delta.x.checked_mul(steps)?)?,
+            y: self.y.checked_add(delta.y.checked_mul(steps)?)?,
+        })
+    }
+
+    pub fn neighbors4(&self) -> [Position; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    pub fn neighbors8(&self) -> [Position; 8] {
+        [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
+            .map(|(x, y)| *self + Position { x, y })
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, other: Position) -> Position {
+        Position { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+
+    fn sub(self, other: Position) -> Position {
+        Position { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<isize> for Position {
+    type Output = Position;
+
+    fn mul(self, factor: isize) -> Position {
+        Position { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl Neg for Position {
+    type Output = Position;
+
+    fn neg(self) -> Position {
+        Position { x: -self.x, y: -self.y }
+    }
+}
+
+impl AddAssign for Position {
+    fn add_assign(&mut self, other: Position) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Position {
+    fn sub_assign(&mut self, other: Position) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/* y grows downwards, as in the puzzle inputs */
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn delta(&self) -> Position {
+        match self {
+            Direction::North => Position { x: 0, y: -1 },
+            Direction::East => Position { x: 1, y: 0 },
+            Direction::South => Position { x: 0, y: 1 },
+            Direction::West => Position { x: -1, y: 0 },
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn(&self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn reverse(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+}
+
+/* polygons are given by their corners in order, the last one connecting back
+   to the first; the edges must not cross */
+
+/* twice the enclosed area, by the shoelace formula, so that it stays integral;
+   None if it doesn't fit */
+pub fn double_area(vertices: &[Position]) -> Option<u64> {
+    let sum = vertices.iter()
+                      .zip(vertices.iter().cycle().skip(1))
+                      .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+                      .sum::<i128>();
+    u64::try_from(sum.unsigned_abs()).ok()
+}
+
+/* lattice points on the edges */
+pub fn boundary_points(vertices: &[Position]) -> u64 {
+    vertices.iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x) as u64, a.y.abs_diff(b.y) as u64))
+            .sum()
+}
+
+/* lattice points strictly inside, by Pick's theorem: A = i + b/2 - 1; None
+   for polygons without an inside, whose edges overlap */
+pub fn interior_points(vertices: &[Position]) -> Option<u64> {
+    (double_area(vertices)? + 2).checked_sub(boundary_points(vertices)).map(|points| points / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let pos = Position::new(3, -2);
+        assert_eq!(pos + Position::new(1, 1), Position::new(4, -1));
+        assert_eq!(pos - Position::new(1, 1), Position::new(2, -3));
+        assert_eq!(pos * 2, Position::new(6, -4));
+        assert_eq!(-pos, Position::new(-3, 2));
+        assert_eq!(pos.manhattan(Position::new(-1, 4)), 10);
+
+        assert_eq!(pos.step(Direction::North), Position::new(3, -3));
+        assert_eq!(pos.step_by(Direction::West, 5), Position::new(-2, -2));
+        assert_eq!(pos.checked_step_by(Direction::West, 5), Some(Position::new(-2, -2)));
+        assert_eq!(pos.checked_step_by(Direction::East, isize::MAX), None);
+        assert_eq!(pos.neighbors4().len(), 4);
+        assert!(pos.neighbors8().iter().all(|n| n.manhattan(pos) <= 2 && *n != pos));
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.delta() + direction.reverse().delta(), Position::default());
+            assert_ne!(direction.is_vertical(), direction.turn(Turn::Left).is_vertical());
+        }
+        assert_eq!(Direction::North.turn(Turn::Right), Direction::East);
+    }
+
+    #[test]
+    fn test_polygon() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| Position::new(x, y));
+        assert_eq!(double_area(&square), Some(32));
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), Some(9));
+
+        /* clockwise order and a diagonal edge */
+        let triangle = [(0, 0), (0, 3), (6, 0)].map(|(x, y)| Position::new(x, y));
+        assert_eq!(double_area(&triangle), Some(18));
+        assert_eq!(boundary_points(&triangle), 3 + 3 + 6);
+        assert_eq!(interior_points(&triangle), Some(4));
+
+        /* there and back again encloses nothing */
+        let line = [(0, 0), (5, 0)].map(|(x, y)| Position::new(x, y));
+        assert_eq!(double_area(&line), Some(0));
+        assert_eq!(boundary_points(&line), 10);
+        assert_eq!(interior_points(&line), None);
+
+        let huge = [(0, 0), (isize::MAX, 0), (isize::MAX, isize::MAX), (0, isize::MAX)].map(|(x, y)| Position::new(x, y));
+        assert_eq!(double_area(&huge), None);
+    }
+}
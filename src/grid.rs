@@ -0,0 +1,158 @@
+use std::ops::{Index, IndexMut};
+use crate::geometry::Position;
+use crate::Error;
+
+/* dense row-major grid, as most puzzle maps are given */
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    pub fn from_lines<S: AsRef<str>>(input: &[S], mut tile: impl FnMut(char) -> T) -> Result<Grid<T>, Error> {
+        let width = input.first().map(|line| line.as_ref().chars().count()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * input.len());
+        for (y, line) in input.iter().enumerate() {
+            let line = line.as_ref();
+            if line.chars().count() != width {
+                return Err(Error::Parse {
+                    line: y + 1,
+                    text: line.to_string(),
+                    message: format!("expected {} columns", width),
+                });
+            }
+            cells.extend(line.chars().map(&mut tile));
+        }
+        Ok(Grid { width, height: input.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, pos: Position) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width .. (y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0 .. self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0 .. self.width).map(|x| self.column(x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0 .. height).flat_map(move |y| (0 .. width).map(move |x| Position { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /* orthogonal neighbours within the grid */
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        pos.neighbors4().into_iter()
+                        .filter(|&pos| self.contains(pos))
+    }
+
+    /* orthogonal and diagonal neighbours within the grid */
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        pos.neighbors8().into_iter()
+                        .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn render(&self, mut tile: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut tile));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).expect("position outside of grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos).expect("position outside of grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "#..",
+            ".#.",
+            "..#",
+            "#.#",
+        ];
+        let mut grid = Grid::from_lines(&input, |c| c == '#').unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.get(Position { x: 1, y: 1 }), Some(&true));
+        assert_eq!(grid.get(Position { x: -1, y: 0 }), None);
+        assert_eq!(grid.get(Position { x: 3, y: 0 }), None);
+        assert_eq!(grid.row(3), [true, false, true]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [false, false, true, true]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().filter(|(_, &c)| c).count(), 5);
+
+        assert_eq!(grid.neighbors4(Position { x: 0, y: 0 }).count(), 2);
+        assert_eq!(grid.neighbors4(Position { x: 1, y: 1 }).count(), 4);
+        assert_eq!(grid.neighbors8(Position { x: 0, y: 0 }).count(), 3);
+        assert_eq!(grid.neighbors8(Position { x: 1, y: 1 }).count(), 8);
+        assert_eq!(grid.neighbors8(Position { x: 2, y: 3 }).count(), 3);
+
+        grid[Position { x: 1, y: 0 }] = true;
+        assert_eq!(grid.render(|&c| if c { '#' } else { '.' }), "##.\n.#.\n..#\n#.#\n");
+
+        assert!(Grid::from_lines(&["..", "..."], |c| c).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+/* half-open interval [start, end) */
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        assert!(start <= end, "invalid interval {}..{}", start, end);
+        Interval { start, end }
+    }
+
+    pub fn with_len(start: i64, len: i64) -> Interval {
+        Interval::new(start, start + len)
+    }
+
+    pub fn inclusive(first: i64, last: i64) -> Interval {
+        Interval::new(first, last + 1)
+    }
+
+    pub fn len(&self) -> i64 {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        if start < end {
+            Some(Interval { start, end })
+        } else {
+            None
+        }
+    }
+
+    /* parts below and at/above the given value; empty parts are omitted */
+    pub fn split_at(&self, at: i64) -> (Option<Interval>, Option<Interval>) {
+        let at = at.clamp(self.start, self.end);
+        let below = Interval { start: self.start, end: at };
+        let above = Interval { start: at, end: self.end };
+        (Some(below).filter(|i| !i.is_empty()), Some(above).filter(|i| !i.is_empty()))
+    }
+
+    pub fn difference(&self, other: &Interval) -> RangeSet {
+        let (below, rest) = self.split_at(other.start);
+        let above = rest.and_then(|rest| rest.split_at(other.end).1);
+        RangeSet::from(below.into_iter().chain(above))
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval { start: self.start + offset, end: self.end + offset }
+    }
+}
+
+/* set of values stored as sorted, disjoint and non-adjacent intervals */
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    pub fn from<I: IntoIterator<Item = Interval>>(intervals: I) -> RangeSet {
+        let mut intervals = intervals.into_iter()
+                                     .filter(|i| !i.is_empty())
+                                     .collect::<Vec<_>>();
+        intervals.sort_unstable();
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        RangeSet { intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /* number of contained values */
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        i < self.intervals.len() && self.intervals[i].contains(value)
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        *self = self.union(&RangeSet::from([interval]));
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        RangeSet::from(self.iter().chain(other.iter()).copied())
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            if let Some(intersection) = a.intersection(b) {
+                intervals.push(intersection);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { intervals }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        for interval in &self.intervals {
+            let mut rest = Some(*interval);
+            for removed in other.iter().filter(|r| r.overlaps(interval)) {
+                let Some(current) = rest else { break };
+                let (below, above) = current.split_at(removed.start);
+                intervals.extend(below);
+                rest = above.and_then(|above| above.split_at(removed.end).1);
+            }
+            intervals.extend(rest);
+        }
+        RangeSet::from(intervals)
+    }
+
+    pub fn split_at(&self, at: i64) -> (RangeSet, RangeSet) {
+        let (below, above): (Vec<_>, Vec<_>) = self.iter().map(|i| i.split_at(at)).unzip();
+        (RangeSet::from(below.into_iter().flatten()), RangeSet::from(above.into_iter().flatten()))
+    }
+
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        RangeSet { intervals: self.iter().map(|i| i.shift(offset)).collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        RangeSet::from(intervals.iter().map(|&(start, end)| Interval::new(start, end)))
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(10, 20);
+        assert_eq!(interval.len(), 10);
+        assert_eq!(Interval::with_len(10, 10), interval);
+        assert_eq!(Interval::inclusive(10, 19), interval);
+        assert!(interval.contains(10) && interval.contains(19) && !interval.contains(20));
+        assert!(Interval::new(5, 5).is_empty());
+
+        assert_eq!(interval.intersection(&Interval::new(15, 30)), Some(Interval::new(15, 20)));
+        assert_eq!(interval.intersection(&Interval::new(20, 30)), None);
+        assert!(!interval.overlaps(&Interval::new(0, 10)));
+
+        assert_eq!(interval.split_at(15), (Some(Interval::new(10, 15)), Some(Interval::new(15, 20))));
+        assert_eq!(interval.split_at(10), (None, Some(interval)));
+        assert_eq!(interval.split_at(25), (Some(interval), None));
+
+        assert_eq!(interval.difference(&Interval::new(12, 14)), set(&[(10, 12), (14, 20)]));
+        assert_eq!(interval.difference(&Interval::new(0, 15)), set(&[(15, 20)]));
+        assert_eq!(interval.difference(&Interval::new(0, 30)), RangeSet::new());
+        assert_eq!(interval.difference(&Interval::new(30, 40)), set(&[(10, 20)]));
+
+        assert_eq!(interval.shift(-15), Interval::new(-5, 5));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let ranges = set(&[(5, 10), (0, 3), (3, 4), (8, 12), (20, 20)]);
+        assert_eq!(ranges.intervals(), [Interval::new(0, 4), Interval::new(5, 12)]);
+        assert_eq!(ranges.len(), 11);
+        assert_eq!(ranges.min(), Some(0));
+        assert_eq!(ranges.max(), Some(11));
+        assert!(ranges.contains(3) && !ranges.contains(4) && ranges.contains(11) && !ranges.contains(12));
+        assert!(RangeSet::new().is_empty());
+        assert_eq!(RangeSet::new().min(), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30), (40, 50)]);
+        let b = set(&[(5, 25), (45, 60)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 60)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (45, 50)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30), (40, 45)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (50, 60)]));
+        assert_eq!(a.difference(&RangeSet::new()), a);
+        assert_eq!(a.intersection(&RangeSet::new()), RangeSet::new());
+
+        /* one interval punched by several holes */
+        let holes = set(&[(1, 2), (4, 5), (8, 12)]);
+        assert_eq!(set(&[(0, 10)]).difference(&holes), set(&[(0, 1), (2, 4), (5, 8)]));
+
+        let mut c = a.clone();
+        c.insert(Interval::new(10, 20));
+        assert_eq!(c, set(&[(0, 30), (40, 50)]));
+
+        /* identities */
+        assert_eq!(a.union(&b).len(), a.len() + b.len() - a.intersection(&b).len());
+        assert_eq!(a.difference(&b).union(&a.intersection(&b)), a);
+    }
+
+    #[test]
+    fn test_split_shift() {
+        let a = set(&[(0, 10), (20, 30)]);
+        assert_eq!(a.split_at(25), (set(&[(0, 10), (20, 25)]), set(&[(25, 30)])));
+        assert_eq!(a.split_at(15), (set(&[(0, 10)]), set(&[(20, 30)])));
+        assert_eq!(a.split_at(-5), (RangeSet::new(), a.clone()));
+        assert_eq!(a.shift(100), set(&[(100, 110), (120, 130)]));
+    }
+}
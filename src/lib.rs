@@ -1,19 +1,176 @@
+use std::fmt::{self, Display};
 use std::str::FromStr;
-use std::fmt::Debug;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
 
-pub fn read_file(day: u8) -> String {
-    let filename = format!("inputs/day{}", day);
-    std::fs::read_to_string(filename).unwrap()
+pub mod answers;
+pub mod cycle;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod output;
+pub mod search;
+pub mod solution;
+
+pub use grid::Grid;
+pub use solution::Solution;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingFile(PathBuf),
+    Io(PathBuf, std::io::Error),
+    Parse { line: usize, text: String, message: String },
+    Usage(String),
+    Invalid(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingFile(path) => write!(f, "input file {} not found", path.display()),
+            Error::Io(path, err) => write!(f, "failed to read {}: {}", path.display(), err),
+            Error::Parse { line, text, message } => write!(f, "line {}: {:?}: {}", line, text, message),
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Invalid(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/* print the error and terminate, instead of panicking with a backtrace */
+pub fn exit_on_error<T>(result: Result<T, Error>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    })
+}
+
+/* environment variable overriding the default "inputs" directory */
+pub const INPUTS_ENV: &str = "ADVENT_INPUTS";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Directory(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_env() -> InputSource {
+        match std::env::var_os(INPUTS_ENV) {
+            Some(dir) => InputSource::Directory(PathBuf::from(dir)),
+            None => InputSource::Directory(PathBuf::from("inputs")),
+        }
+    }
+
+    /* "--inputs DIR", "--input FILE" or "-" for stdin; falls back to the environment */
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<InputSource, Error> {
+        let mut source = None;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let next_source = match arg.as_str() {
+                "--inputs" => InputSource::Directory(PathBuf::from(option_value(&mut args, &arg)?)),
+                "--input" => InputSource::File(PathBuf::from(option_value(&mut args, &arg)?)),
+                "-" | "--stdin" => InputSource::Stdin,
+                _ => return Err(Error::Usage(format!("unexpected argument {:?}", arg))),
+            };
+            if source.replace(next_source).is_some() {
+                return Err(Error::Usage("only one input source can be given".to_string()));
+            }
+        }
+        Ok(source.unwrap_or_else(InputSource::from_env))
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, Error> {
+        match self {
+            InputSource::Directory(dir) => read_path(&dir.join(format!("day{}", day))),
+            InputSource::File(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)
+                                .map_err(|err| Error::Io(PathBuf::from("<stdin>"), err))?;
+                Ok(input)
+            },
+        }
+    }
+
+    pub fn read_lines(&self, day: u8) -> Result<Vec<String>, Error> {
+        Ok(lines(&self.read(day)?))
+    }
+
+    /* one number per line */
+    pub fn read_numbers<T: FromStr>(&self, day: u8) -> Result<Vec<T>, Error> where <T as FromStr>::Err: Display {
+        parse_lines(&self.read(day)?, |line| line.parse().map_err(|err: T::Err| err.to_string()))
+    }
 }
 
-pub fn read_lines(day: u8) -> Vec<String> {
-    read_file(day).split_terminator('\n')
-                  .map(String::from)
-                  .collect()
+pub fn option_value<I: Iterator<Item = String>>(args: &mut I, option: &str) -> Result<String, Error> {
+    args.next().ok_or_else(|| Error::Usage(format!("{} requires a value", option)))
 }
 
-pub fn read_numbers<T: FromStr>(day: u8) -> Vec<T> where <T as FromStr>::Err: Debug {
-    read_lines(day).iter()
-                   .map(|n| n.parse::<T>().unwrap())
-                   .collect::<Vec<T>>()
+fn read_path(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|err| match err.kind() {
+        ErrorKind::NotFound => Error::MissingFile(path.to_path_buf()),
+        _ => Error::Io(path.to_path_buf(), err),
+    })
+}
+
+pub fn lines(input: &str) -> Vec<String> {
+    input.split_terminator('\n')
+         .map(String::from)
+         .collect()
+}
+
+/* parses each line on its own; the first failure is reported with its line number */
+pub fn parse_lines<T>(input: &str, mut parse: impl FnMut(&str) -> Result<T, String>) -> Result<Vec<T>, Error> {
+    lines(input).into_iter()
+                .enumerate()
+                .map(|(i, line)| parse(&line).map_err(|message| Error::Parse { line: i + 1, text: line, message }))
+                .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source() {
+        let args = |args: &[&str]| args.iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+        assert_eq!(InputSource::from_args(args(&["--inputs", "/tmp/aoc"])).unwrap(),
+                   InputSource::Directory(PathBuf::from("/tmp/aoc")));
+        assert_eq!(InputSource::from_args(args(&["--input", "inputs/day5.example"])).unwrap(),
+                   InputSource::File(PathBuf::from("inputs/day5.example")));
+        assert_eq!(InputSource::from_args(args(&["-"])).unwrap(), InputSource::Stdin);
+        assert!(InputSource::from_args(args(&["--input"])).is_err());
+        assert!(InputSource::from_args(args(&["--stdin", "--inputs", "x"])).is_err());
+        assert!(InputSource::from_args(args(&["--foo"])).is_err());
+
+        let path = std::env::temp_dir().join(format!("advent-numbers-{}", std::process::id()));
+        std::fs::write(&path, "1\n-2\nx\n").unwrap();
+        let source = InputSource::File(path.clone());
+        assert_eq!(source.read_lines(1).unwrap(), ["1", "-2", "x"]);
+        assert!(matches!(source.read_numbers::<i32>(1), Err(Error::Parse { line: 3, .. })));
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(source.read_numbers::<i32>(1), Err(Error::MissingFile(_))));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| line.parse::<u32>().map_err(|err| err.to_string());
+        assert_eq!(parse_lines("1\n2\n3\n", parse).unwrap(), [1, 2, 3]);
+        match parse_lines("1\nx\n3", parse) {
+            Err(Error::Parse { line, text, .. }) => assert_eq!((line, text.as_str()), (2, "x")),
+            _ => panic!("expected a parse error"),
+        }
+    }
 }
@@ -0,0 +1,246 @@
+use advent::days;
+use advent::answers;
+use advent::output::{self, Format, Record};
+use advent::solution::{self, Day, Stats};
+use advent::{Error, InputSource};
+
+const USAGE: &str = "usage: advent [verify] [DAY | FIRST-LAST | all]... [--inputs DIR | --input FILE | -] [--time | --bench N | --render] [--format text|json|csv]";
+
+struct Options {
+    days: Vec<Day>,
+    source: InputSource,
+    show_time: bool,
+    bench: Option<usize>,
+    render: bool,
+    verify: bool,
+    format: Format,
+}
+
+fn parse_days(spec: &str) -> Result<Vec<Day>, Error> {
+    let invalid = || Error::Usage(format!("invalid day {:?}\n{}", spec, USAGE));
+    if spec == "all" {
+        return Ok(days::DAYS.to_vec());
+    }
+    if let Some((first, last)) = spec.split_once('-') {
+        let first = first.parse::<u8>().map_err(|_| invalid())?;
+        let last = last.parse::<u8>().map_err(|_| invalid())?;
+        /* ranges only include implemented days */
+        return Ok(days::DAYS.iter()
+                            .filter(|d| (first ..= last).contains(&d.day))
+                            .copied()
+                            .collect());
+    }
+    let day = spec.parse::<u8>().map_err(|_| invalid())?;
+    match days::find(day) {
+        Some(day) => Ok(vec![day]),
+        None => Err(Error::Usage(format!("day {} is not implemented", day))),
+    }
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Options, Error> {
+    let mut days = Vec::new();
+    let mut source_args = Vec::new();
+    let mut show_time = false;
+    let mut bench = None;
+    let mut render = false;
+    let mut verify = false;
+    let mut format = Format::Text;
+    let mut args = args.into_iter().peekable();
+    if args.next_if(|arg| arg == "verify").is_some() {
+        verify = true;
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--inputs" | "--input" => {
+                let value = advent::option_value(&mut args, &arg)?;
+                source_args.push(arg);
+                source_args.push(value);
+            },
+            "-" | "--stdin" => source_args.push(arg),
+            "--time" => show_time = true,
+            "--bench" => {
+                let value = advent::option_value(&mut args, &arg)?;
+                match value.parse::<usize>() {
+                    Ok(n) if n > 0 => bench = Some(n),
+                    _ => return Err(Error::Usage(format!("invalid iteration count {:?}", value))),
+                }
+            },
+            "--render" => render = true,
+            "--format" => format = Format::from(&advent::option_value(&mut args, &arg)?)?,
+            "-h" | "--help" => return Err(Error::Usage(USAGE.to_string())),
+            spec => days.extend(parse_days(spec)?),
+        }
+    }
+    if days.is_empty() {
+        days = days::DAYS.to_vec();
+    }
+
+    let source = InputSource::from_args(source_args)?;
+    if days.len() > 1 && !matches!(source, InputSource::Directory(_)) {
+        return Err(Error::Usage("an input file or stdin can only be used with a single day".to_string()));
+    }
+    if verify && bench.is_some() {
+        return Err(Error::Usage("verify can't be combined with --bench".to_string()));
+    }
+    if format != Format::Text && bench.is_some() {
+        return Err(Error::Usage("--bench only supports text output".to_string()));
+    }
+    if render && (verify || bench.is_some() || format != Format::Text) {
+        return Err(Error::Usage("--render can't be combined with verify, --bench or --format".to_string()));
+    }
+    Ok(Options { days, source, show_time, bench, render, verify, format })
+}
+
+fn day_records(day: &Day, options: &Options) -> Result<Vec<Record>, Error> {
+    let expected = if options.verify {
+        Some(answers::read(&answers::answers_dir(&options.source)?, day.day)?)
+    } else {
+        None
+    };
+    let input = options.source.read(day.day)?;
+    let result = (day.run)(&input)?;
+    print_warnings(day, &result.warnings);
+    Ok(Record::from_result(&result, expected.as_ref()))
+}
+
+/* on stderr, so that they don't mix with json or csv output */
+fn print_warnings(day: &Day, warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("{}: warning: {}", day.day, warning);
+    }
+}
+
+/* days without an input are skipped when running more than one day, unless
+   there are expected answers to check them against */
+fn is_skipped(day: &Day, err: &Error, options: &Options) -> bool {
+    let answers = answers::answers_dir(&options.source).and_then(|dir| answers::read(&dir, day.day));
+    matches!(err, Error::MissingFile(_)) && options.days.len() > 1 && matches!(answers, Ok([None, None]))
+}
+
+fn print_text(record: &Record, options: &Options) {
+    let answer = record.answer.as_deref().unwrap_or_default();
+    match record.part {
+        None => eprintln!("{}: {}: {}", record.day, record.status, record.message.as_deref().unwrap_or_default()),
+        Some("parse") => if options.show_time && !options.verify {
+            println!("{} parse: ({:.2?})", record.day, record.time.unwrap_or_default());
+        },
+        Some(part) => if options.verify {
+            println!("{:>3}{}  {:<7}  {:>20}  {:>20}",
+                     record.day, part, record.status, record.expected.as_deref().unwrap_or("-"), answer);
+        } else if options.show_time {
+            println!("{}{}: {} ({:.2?})", record.day, part, answer, record.time.unwrap_or_default());
+        } else {
+            println!("{}{}: {}", record.day, part, answer);
+        },
+    }
+}
+
+fn bench_day(day: &Day, options: &Options, iterations: usize) -> Result<(), Error> {
+    let input = options.source.read(day.day)?;
+    let benchmark = solution::benchmark(day, &input, iterations)?;
+    print_warnings(day, &benchmark.warnings);
+    let print_stats = |label: String, stats: &Stats| {
+        println!("{:<8} min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+                 label, stats.min, stats.median, stats.max);
+    };
+    print_stats(format!("{} parse:", day.day), &benchmark.parse);
+    print_stats(format!("{}a:", day.day), &benchmark.parts[0]);
+    print_stats(format!("{}b:", day.day), &benchmark.parts[1]);
+    Ok(())
+}
+
+fn render_day(day: &Day, options: &Options) -> Result<(), Error> {
+    let input = options.source.read(day.day)?;
+    let rendering = (day.render)(&input)?;
+    print_warnings(day, &rendering.warnings);
+    match rendering.picture {
+        Some(picture) => print!("{}", picture),
+        None => return Err(Error::Usage(format!("day {} has no render mode", day.day))),
+    }
+    Ok(())
+}
+
+fn main() {
+    let options = advent::exit_on_error(parse_args(std::env::args().skip(1)));
+
+    let mut failed = false;
+    if let Some(iterations) = options.bench {
+        for day in &options.days {
+            match bench_day(day, &options, iterations) {
+                Err(err) if is_skipped(day, &err, &options) => eprintln!("{}: skipped: {}", day.day, err),
+                Err(err) => { eprintln!("{}: error: {}", day.day, err); failed = true; },
+                Ok(()) => {},
+            }
+        }
+    } else if options.render {
+        for day in &options.days {
+            match render_day(day, &options) {
+                Err(err) if is_skipped(day, &err, &options) => eprintln!("{}: skipped: {}", day.day, err),
+                Err(err) => { eprintln!("{}: error: {}", day.day, err); failed = true; },
+                Ok(()) => {},
+            }
+        }
+    } else {
+        match options.format {
+            Format::Text if options.verify => {
+                println!("{:>4}  {:<7}  {:>20}  {:>20}", "day", "status", "expected", "actual");
+            },
+            Format::Csv => println!("{}", output::CSV_HEADER),
+            _ => {},
+        }
+        for day in &options.days {
+            let records = day_records(day, &options).unwrap_or_else(|err| if is_skipped(day, &err, &options) {
+                vec![Record::skipped(day.day, &err)]
+            } else {
+                vec![Record::error(day.day, &err)]
+            });
+            for record in &records {
+                failed |= matches!(record.status, "fail" | "error");
+                match options.format {
+                    Format::Text => print_text(record, &options),
+                    Format::Json => println!("{}", record.to_json()),
+                    Format::Csv => println!("{}", record.to_csv()),
+                }
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let days = |spec| parse_days(spec).unwrap().iter().map(|d| d.day).collect::<Vec<_>>();
+        assert_eq!(days("5"), [5]);
+        assert_eq!(days("9-13"), [9, 10, 11, 12, 13]);
+        assert_eq!(days("19-22"), [19]);
+        assert_eq!(days("all").len(), days::DAYS.len());
+        assert!(parse_days("20").is_err());
+        assert!(parse_days("x-3").is_err());
+
+        let args = |args: &[&str]| parse_args(args.iter().map(|&x| String::from(x)));
+        assert!(args(&["5", "--input", "inputs/day5.example"]).is_ok());
+        assert!(args(&["1-3", "-"]).is_err());
+        assert_eq!(args(&["--bench", "10"]).unwrap().bench, Some(10));
+        assert!(args(&["--bench", "0"]).is_err());
+        assert!(args(&["verify", "1-5"]).unwrap().verify);
+        assert!(args(&["verify", "--bench", "3"]).is_err());
+        assert_eq!(args(&["--format", "json"]).unwrap().format, Format::Json);
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["10", "--render"]).unwrap().render);
+        assert!(args(&["verify", "10", "--render"]).is_err());
+        assert!(args(&["10", "--render", "--format", "csv"]).is_err());
+
+        /* a missing input only skips days that have no expected answers */
+        let options = args(&["verify"]).unwrap();
+        let missing = Error::MissingFile("inputs/dayN".into());
+        assert!(is_skipped(&days::find(17).unwrap(), &missing, &options));
+        assert!(!is_skipped(&days::find(9).unwrap(), &missing, &options));
+        assert!(!is_skipped(&days::find(17).unwrap(), &missing, &args(&["verify", "17"]).unwrap()));
+    }
+}
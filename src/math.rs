@@ -0,0 +1,125 @@
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/* divide before multiplying, so only a result that doesn't fit overflows */
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("lcm overflows u64")
+}
+
+pub fn gcd_all(values: &[u64]) -> u64 {
+    values.iter().fold(0, |acc, &value| gcd(acc, value))
+}
+
+pub fn lcm_all(values: &[u64]) -> Option<u64> {
+    values.iter().try_fold(1, |acc, &value| checked_lcm(acc, value))
+}
+
+/* returns (g, x, y) with a*x + b*y = g = gcd(a, b) */
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    let (mut old_t, mut t) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+    if old_r < 0 {
+        (old_r, old_s, old_t) = (-old_r, -old_s, -old_t);
+    }
+    (old_r as i64, old_s as i64, old_t as i64)
+}
+
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0);
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(modulus))
+}
+
+/* solve x = residue (mod modulus) for all given pairs; the moduli don't need
+   to be coprime. returns the smallest non-negative x and the combined modulus,
+   or None if the congruences contradict each other or the modulus overflows */
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut result = 0i128;
+    let mut modulus = 1i128;
+    for &(residue, m) in congruences {
+        assert!(m > 0);
+        let m = m as i128;
+        let residue = (residue as i128).rem_euclid(m);
+
+        let (g, p, _) = extended_gcd(modulus as i64, m as i64);
+        let (g, p) = (g as i128, p as i128);
+        let diff = residue - result;
+        if diff % g != 0 {
+            return None;
+        }
+        let lcm = modulus / g * m;
+        if lcm > i64::MAX as i128 {
+            return None;
+        }
+        /* result + modulus * k = residue (mod m)  =>  k = diff/g * p (mod m/g) */
+        let k = (diff / g * p).rem_euclid(m / g);
+        result = (result + modulus * k).rem_euclid(lcm);
+        modulus = lcm;
+    }
+    Some((result as i64, modulus as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(gcd_all(&[12, 18, 30]), 6);
+        assert_eq!(lcm_all(&[2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(&[]), Some(1));
+
+        /* a*b overflows, but the lcm itself fits */
+        let big = 1 << 40;
+        assert_eq!(checked_lcm(big, big * 3), Some(big * 3));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-12, 18), (17, 5), (0, 9)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        /* non-coprime moduli */
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(0, 6), (0, 4)]), Some((0, 12)));
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,174 @@
+use std::time::Duration;
+use crate::answers::Status;
+use crate::solution::DayResult;
+use crate::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from(input: &str) -> Result<Format, Error> {
+        match input {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(Error::Usage(format!("unknown output format {:?}", input))),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,expected,time_ns,status,message";
+
+/* one line of structured output; the part is "parse", "a" or "b" and
+   missing for errors that affect the whole day */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Option<&'static str>,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub time: Option<Duration>,
+    pub status: &'static str,
+    pub message: Option<String>,
+}
+
+impl Record {
+    pub fn from_result(result: &DayResult, expected: Option<&[Option<String>; 2]>) -> Vec<Record> {
+        let mut records = vec![Record {
+            day: result.day,
+            part: Some("parse"),
+            answer: None,
+            expected: None,
+            time: Some(result.parse_time),
+            status: "ok",
+            message: None,
+        }];
+        for (i, (part, name)) in result.parts.iter().zip(["a", "b"]).enumerate() {
+            let expected = expected.map(|expected| expected[i].clone());
+            let status = match &expected {
+                Some(expected) => Status::check(expected.as_deref(), &part.answer).name(),
+                None => "ok",
+            };
+            records.push(Record {
+                day: result.day,
+                part: Some(name),
+                answer: Some(part.answer.clone()),
+                expected: expected.flatten(),
+                time: Some(part.time),
+                status,
+                message: None,
+            });
+        }
+        records
+    }
+
+    pub fn error(day: u8, err: &Error) -> Record {
+        Record {
+            day,
+            part: None,
+            answer: None,
+            expected: None,
+            time: None,
+            status: "error",
+            message: Some(err.to_string()),
+        }
+    }
+
+    /* a day that wasn't run, e.g. for lack of an input */
+    pub fn skipped(day: u8, err: &Error) -> Record {
+        Record { status: "skipped", ..Record::error(day, err) }
+    }
+
+    pub fn to_json(&self) -> String {
+        let string = |value: Option<&str>| value.map(json_string).unwrap_or_else(|| "null".to_string());
+        format!("{{\"day\":{},\"part\":{},\"answer\":{},\"expected\":{},\"time_ns\":{},\"status\":{},\"message\":{}}}",
+                self.day,
+                string(self.part),
+                string(self.answer.as_deref()),
+                string(self.expected.as_deref()),
+                self.time.map(|t| t.as_nanos().to_string()).unwrap_or_else(|| "null".to_string()),
+                json_string(self.status),
+                string(self.message.as_deref()))
+    }
+
+    pub fn to_csv(&self) -> String {
+        let field = |value: Option<&str>| value.map(csv_field).unwrap_or_default();
+        [
+            self.day.to_string(),
+            field(self.part),
+            field(self.answer.as_deref()),
+            field(self.expected.as_deref()),
+            self.time.map(|t| t.as_nanos().to_string()).unwrap_or_default(),
+            csv_field(self.status),
+            field(self.message.as_deref()),
+        ].join(",")
+    }
+}
+
+fn json_string(input: &str) -> String {
+    let mut output = String::from("\"");
+    for c in input.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+fn csv_field(input: &str) -> String {
+    if input.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", input.replace('"', "\"\""))
+    } else {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::PartResult;
+
+    #[test]
+    fn test() {
+        let result = DayResult {
+            day: 5,
+            parse_time: Duration::from_nanos(1500),
+            parts: [
+                PartResult { answer: "35".to_string(), time: Duration::from_nanos(42) },
+                PartResult { answer: "46".to_string(), time: Duration::from_millis(3) },
+            ],
+            warnings: Vec::new(),
+        };
+        let records = Record::from_result(&result, None);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].to_json(),
+                   r#"{"day":5,"part":"parse","answer":null,"expected":null,"time_ns":1500,"status":"ok","message":null}"#);
+        assert_eq!(records[1].to_json(),
+                   r#"{"day":5,"part":"a","answer":"35","expected":null,"time_ns":42,"status":"ok","message":null}"#);
+        assert_eq!(records[2].to_csv(), "5,b,46,,3000000,ok,");
+
+        let expected = [Some("35".to_string()), Some("47".to_string())];
+        let records = Record::from_result(&result, Some(&expected));
+        assert_eq!(records[1].status, "pass");
+        assert_eq!(records[2].to_csv(), "5,b,46,47,3000000,fail,");
+
+        let record = Record::error(12, &Error::Usage("no \"input\", sorry".to_string()));
+        assert_eq!(record.to_json(),
+                   r#"{"day":12,"part":null,"answer":null,"expected":null,"time_ns":null,"status":"error","message":"no \"input\", sorry"}"#);
+        assert_eq!(record.to_csv(), r#"12,,,,,error,"no ""input"", sorry""#);
+
+        let record = Record::skipped(17, &Error::MissingFile("inputs/day17".into()));
+        assert_eq!(record.to_csv(), "17,,,,,skipped,input file inputs/day17 not found");
+    }
+}
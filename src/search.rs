@@ -0,0 +1,99 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/* cheapest goal state reachable from any of the start states, with its cost;
+   `neighbors` yields the states reachable in one move and what the move costs */
+pub fn dijkstra<S, I>(starts: impl IntoIterator<Item = S>,
+                      mut neighbors: impl FnMut(&S) -> I,
+                      mut is_goal: impl FnMut(&S) -> bool) -> Option<(S, u64)>
+    where S: Hash + Eq + Clone, I: IntoIterator<Item = (S, u64)> {
+    /* states live in a vector, so the heap only needs to order their indices */
+    let mut states = Vec::new();
+    let mut costs = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !costs.contains_key(&start) {
+            costs.insert(start.clone(), 0);
+            queue.push(Reverse((0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        let state = &states[index];
+        if costs[state] < cost {
+            /* already reached more cheaply */
+            continue;
+        }
+        if is_goal(state) {
+            return Some((states.swap_remove(index), cost));
+        }
+        for (next, step) in neighbors(state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            queue.push(Reverse((next_cost, states.len())));
+            states.push(next);
+        }
+    }
+    None
+}
+
+/* all states reachable from the start states, found breadth-first without
+   recursion, so that large areas don't overflow the stack */
+pub fn flood_fill<S, I>(starts: impl IntoIterator<Item = S>, mut neighbors: impl FnMut(&S) -> I) -> HashSet<S>
+    where S: Hash + Eq + Clone, I: IntoIterator<Item = S> {
+    let mut reached = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if reached.insert(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        for next in neighbors(&state) {
+            if reached.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        /* 0 -> 1 -> 3 is cheaper than the direct edge 0 -> 3 */
+        let edges = [(0, 1, 1), (1, 3, 2), (0, 3, 5), (0, 2, 1), (2, 1, 1)];
+        let neighbors = |&node: &u32| edges.iter()
+                                           .filter(move |&&(from, _, _)| from == node)
+                                           .map(|&(_, to, cost)| (to, cost))
+                                           .collect::<Vec<_>>();
+        assert_eq!(dijkstra([0], neighbors, |&node| node == 3), Some((3, 3)));
+        assert_eq!(dijkstra([2, 0], neighbors, |&node| node == 1), Some((1, 1)));
+        assert_eq!(dijkstra([3], neighbors, |&node| node == 0), None);
+        assert_eq!(dijkstra([0], neighbors, |&node| node == 0), Some((0, 0)));
+
+        /* the goal may depend on more than the node */
+        let neighbors = |&(node, steps): &(u32, u32)| (1 ..= 2).map(move |d| ((node + d, steps + 1), d as u64));
+        assert_eq!(dijkstra([(0, 0)], neighbors, |&(node, steps)| node == 6 && steps == 5), Some(((6, 5), 6)));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        /* the region 0..10 except 5, reachable from 2 and 7 */
+        let neighbors = |&n: &i32| [n - 1, n + 1].into_iter().filter(|&n| (0 .. 10).contains(&n) && n != 5);
+        assert_eq!(flood_fill([2], neighbors), HashSet::from([0, 1, 2, 3, 4]));
+        assert_eq!(flood_fill([2, 7], neighbors).len(), 9);
+        assert!(flood_fill(Vec::<i32>::new(), neighbors).is_empty());
+
+        /* far deeper than the call stack could go */
+        assert_eq!(flood_fill([0], |&n: &u32| (n < 200_000).then_some(n + 1)).len(), 200_001);
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+use crate::Error;
+
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    /* parts fail on input that parses but has no answer */
+    fn part_a(input: &Self::Input) -> Result<impl Display, Error>;
+    fn part_b(input: &Self::Input) -> Result<impl Display, Error>;
+
+    /* optional picture of the puzzle state, for debugging */
+    fn render(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /* problems with the input that don't keep it from being solved */
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub answer: String,
+    pub time: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: [PartResult; 2],
+    pub warnings: Vec<String>,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub fn run<S: Solution>(input: &str) -> Result<DayResult, Error> {
+    let (input, parse_time) = timed(|| S::parse(input));
+    let input = input?;
+    let (answer_a, time_a) = timed(|| S::part_a(&input).map(|answer| answer.to_string()));
+    let (answer_b, time_b) = timed(|| S::part_b(&input).map(|answer| answer.to_string()));
+    let (answer_a, answer_b) = (answer_a?, answer_b?);
+    Ok(DayResult {
+        day: S::DAY,
+        parse_time,
+        parts: [
+            PartResult { answer: answer_a, time: time_a },
+            PartResult { answer: answer_b, time: time_b },
+        ],
+        warnings: S::warnings(&input),
+    })
+}
+
+/* the picture, if the day has one, and the warnings about the input */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rendering {
+    pub picture: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+pub fn render<S: Solution>(input: &str) -> Result<Rendering, Error> {
+    let input = S::parse(input)?;
+    Ok(Rendering { picture: S::render(&input), warnings: S::warnings(&input) })
+}
+
+/* type-erased entry for the runner */
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str) -> Result<DayResult, Error>,
+    pub render: fn(&str) -> Result<Rendering, Error>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day { day: S::DAY, run: run::<S>, render: render::<S> }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(times: &mut [Duration]) -> Stats {
+        times.sort_unstable();
+        Stats {
+            min: times[0],
+            median: times[times.len() / 2],
+            max: times[times.len() - 1],
+        }
+    }
+}
+
+pub struct Benchmark {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: [Stats; 2],
+    pub warnings: Vec<String>,
+}
+
+pub fn benchmark(day: &Day, input: &str, iterations: usize) -> Result<Benchmark, Error> {
+    assert!(iterations > 0);
+    let mut parse_times = Vec::new();
+    let mut times = [Vec::new(), Vec::new()];
+    let mut warnings = Vec::new();
+    for _ in 0 .. iterations {
+        let result = (day.run)(input)?;
+        /* every iteration parses the same input */
+        warnings = result.warnings;
+        parse_times.push(result.parse_time);
+        for (part, times) in result.parts.iter().zip(times.iter_mut()) {
+            times.push(part.time);
+        }
+    }
+    Ok(Benchmark {
+        day: day.day,
+        parse: Stats::new(&mut parse_times),
+        parts: [Stats::new(&mut times[0]), Stats::new(&mut times[1])],
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(input.split_whitespace().map(|x| x.parse().unwrap()).collect())
+        }
+
+        fn part_a(input: &Self::Input) -> Result<impl Display, Error> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part_b(input: &Self::Input) -> Result<impl Display, Error> {
+            input.iter()
+                 .try_fold(1u32, |product, &x| product.checked_mul(x))
+                 .ok_or_else(|| Error::Invalid("product overflows".to_string()))
+        }
+    }
+
+    #[test]
+    fn test() {
+        let day = Day::of::<Sum>();
+        let result = (day.run)("2 3 4").unwrap();
+        assert_eq!(result.day, 0);
+        assert_eq!(result.parts[0].answer, "9");
+        assert_eq!(result.parts[1].answer, "24");
+        assert!(result.warnings.is_empty());
+        assert_eq!((day.render)("2 3 4").unwrap(), Rendering { picture: None, warnings: Vec::new() });
+        assert!((day.run)("65536 65536").is_err());
+
+        let benchmark = benchmark(&day, "2 3 4", 5).unwrap();
+        assert!(benchmark.parts[0].min <= benchmark.parts[0].median);
+        assert!(benchmark.parts[0].median <= benchmark.parts[0].max);
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let mut times = [ms(5), ms(1), ms(9), ms(3), ms(4)];
+        assert_eq!(Stats::new(&mut times), Stats { min: ms(1), median: ms(4), max: ms(9) });
+    }
+}